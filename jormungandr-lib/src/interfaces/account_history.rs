@@ -0,0 +1,11 @@
+use crate::crypto::hash::Hash;
+use serde::{Deserialize, Serialize};
+
+/// a fragment in which an account is involved, either as an input or as an
+/// output, along with the chain length of the block including it
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AccountHistoryEntry {
+    pub fragment_id: Hash,
+    pub chain_length: u32,
+}
@@ -53,6 +53,18 @@ pub struct Block0Configuration {
     pub initial: Vec<Initial>,
 }
 
+/// a field differing between two block0 configurations, the values being
+/// given in their debug representation
+///
+/// For the initial fragments, `left` is `None` if the fragment was added
+/// and `right` is `None` if it was removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    pub field: String,
+    pub left: Option<String>,
+    pub right: Option<String>,
+}
+
 #[derive(Debug, Error)]
 pub enum Block0ConfigurationError {
     #[error("Invalid block, expecting the first block fragment to be an special Init fragment")]
@@ -96,6 +108,119 @@ impl Block0Configuration {
         })
         .expect("internal error: block builder cannot return error")
     }
+
+    /// compare with another block0 configuration, the initial fragments
+    /// being compared regardless of their order
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        if self.blockchain_configuration != other.blockchain_configuration
+            || self.initial.len() != other.initial.len()
+        {
+            return false;
+        }
+
+        let mut unmatched: Vec<&Initial> = other.initial.iter().collect();
+        self.initial.iter().all(|initial| {
+            match unmatched.iter().position(|other| *other == initial) {
+                Some(index) => {
+                    unmatched.swap_remove(index);
+                    true
+                }
+                None => false,
+            }
+        })
+    }
+
+    /// list the blockchain configuration fields differing from `other`,
+    /// followed by the initial fragments only present in one of them
+    pub fn diff(&self, other: &Self) -> Vec<FieldDiff> {
+        // destructured without `..`: a new field fails to compile, and is
+        // reported as unused until it is listed in `diff_fields!`
+        let Block0Configuration {
+            blockchain_configuration: left,
+            initial: left_initial,
+        } = self;
+        let Block0Configuration {
+            blockchain_configuration: right,
+            initial: right_initial,
+        } = other;
+        let BlockchainConfiguration {
+            block0_date,
+            discrimination,
+            block0_consensus,
+            consensus_leader_ids,
+            linear_fees,
+            slots_per_epoch,
+            slot_duration,
+            kes_update_speed,
+            consensus_genesis_praos_active_slot_coeff,
+            block_content_max_size,
+            epoch_stability_depth,
+            fees_go_to,
+            treasury,
+            treasury_parameters,
+            total_reward_supply,
+            reward_parameters,
+            reward_constraints,
+            committees,
+        } = left;
+        let mut diffs = Vec::new();
+
+        macro_rules! diff_fields {
+            ($($field:ident),*) => {
+                $(
+                    if *$field != right.$field {
+                        diffs.push(FieldDiff {
+                            field: stringify!($field).to_owned(),
+                            left: Some(format!("{:?}", $field)),
+                            right: Some(format!("{:?}", right.$field)),
+                        });
+                    }
+                )*
+            };
+        }
+
+        diff_fields!(
+            block0_date,
+            discrimination,
+            block0_consensus,
+            consensus_leader_ids,
+            linear_fees,
+            slots_per_epoch,
+            slot_duration,
+            kes_update_speed,
+            consensus_genesis_praos_active_slot_coeff,
+            block_content_max_size,
+            epoch_stability_depth,
+            fees_go_to,
+            treasury,
+            treasury_parameters,
+            total_reward_supply,
+            reward_parameters,
+            reward_constraints,
+            committees
+        );
+
+        let mut added: Vec<&Initial> = right_initial.iter().collect();
+        for initial in left_initial {
+            match added.iter().position(|other| *other == initial) {
+                Some(index) => {
+                    added.remove(index);
+                }
+                None => diffs.push(FieldDiff {
+                    field: "initial".to_owned(),
+                    left: Some(format!("{:?}", initial)),
+                    right: None,
+                }),
+            }
+        }
+        diffs.extend(added.into_iter().map(|initial| FieldDiff {
+            field: "initial".to_owned(),
+            left: None,
+            right: Some(format!("{:?}", initial)),
+        }));
+
+        diffs
+    }
 }
 
 pub fn block0_configuration_documented_example() -> String {
@@ -154,6 +279,27 @@ mod test {
         }
     }
 
+    #[test]
+    fn diff_reports_only_the_changed_slot_duration() {
+        let left: Block0Configuration =
+            serde_yaml::from_str(&block0_configuration_documented_example()).unwrap();
+        let mut right = left.clone();
+        right.blockchain_configuration.slot_duration = SlotDuration::new(7).unwrap();
+
+        assert_eq!(
+            left.diff(&right),
+            vec![FieldDiff {
+                field: "slot_duration".to_owned(),
+                left: Some(format!("{:?}", left.blockchain_configuration.slot_duration)),
+                right: Some(format!(
+                    "{:?}",
+                    right.blockchain_configuration.slot_duration
+                )),
+            }]
+        );
+        assert!(left.diff(&left).is_empty());
+    }
+
     #[test]
     fn documented_example_decodes() {
         let _: Block0Configuration =
@@ -174,5 +320,12 @@ mod test {
 
             TestResult::from_bool(block0_configuration == block0_configuration_dec)
         }
+
+        fn block0_configuration_semantically_eq_ignores_initial_order(block0_configuration: Block0Configuration) -> TestResult {
+            let mut reordered = block0_configuration.clone();
+            reordered.initial.reverse();
+
+            TestResult::from_bool(block0_configuration.semantically_eq(&reordered))
+        }
     }
 }
@@ -1,10 +1,42 @@
+use crate::interfaces::NumberOfSlotsPerEpoch;
 use chain_impl_mockchain::block;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{fmt, str::FromStr};
 
+/// date of a slot in the blockchain. Dates are ordered by epoch first,
+/// then by slot within the epoch.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct BlockDate(block::BlockDate);
 
+impl BlockDate {
+    pub fn new(epoch: u32, slot_id: u32) -> Self {
+        BlockDate(block::BlockDate { epoch, slot_id })
+    }
+
+    pub fn epoch(&self) -> u32 {
+        self.0.epoch
+    }
+
+    pub fn slot(&self) -> u32 {
+        self.0.slot_id
+    }
+
+    /// the date of the following slot, which is the first slot of the next
+    /// epoch if this is the last slot of the epoch
+    pub fn next_slot(&self, slots_per_epoch: NumberOfSlotsPerEpoch) -> Self {
+        let slots_per_epoch: u32 = slots_per_epoch.into();
+        if self.0.slot_id + 1 >= slots_per_epoch {
+            BlockDate::new(self.0.epoch + 1, 0)
+        } else {
+            BlockDate::new(self.0.epoch, self.0.slot_id + 1)
+        }
+    }
+
+    pub fn is_before(&self, other: &Self) -> bool {
+        self < other
+    }
+}
+
 /* ---------------- Display ------------------------------------------------ */
 
 impl fmt::Display for BlockDate {
@@ -107,6 +139,32 @@ mod test {
         assert_eq!(date.to_string(), "12.928")
     }
 
+    #[test]
+    fn ordering_across_epoch_boundary() {
+        let last_slot = BlockDate::new(1, 59);
+        let first_slot = BlockDate::new(2, 0);
+
+        assert!(last_slot < first_slot);
+        assert!(last_slot.is_before(&first_slot));
+        assert!(!first_slot.is_before(&last_slot));
+        assert!(!last_slot.is_before(&last_slot));
+        assert!(BlockDate::new(1, 0) < BlockDate::new(1, 1));
+    }
+
+    #[test]
+    fn next_slot_rolls_over_to_next_epoch() {
+        let slots_per_epoch = NumberOfSlotsPerEpoch::new(60).unwrap();
+
+        assert_eq!(
+            BlockDate::new(1, 58).next_slot(slots_per_epoch),
+            BlockDate::new(1, 59)
+        );
+        assert_eq!(
+            BlockDate::new(1, 59).next_slot(slots_per_epoch),
+            BlockDate::new(2, 0)
+        );
+    }
+
     quickcheck! {
         fn display_and_from_str(date: BlockDate) -> TestResult {
             let encoded = date.to_string();
@@ -1,6 +1,8 @@
+use crate::crypto::key::Identifier;
 use bech32::{self, FromBase32 as _, ToBase32 as _};
 use chain_core::mempack::{ReadBuf, ReadError, Readable};
 use chain_core::property;
+use chain_crypto::{Curve25519_2HashDH, Ed25519, SumEd25519_12};
 use chain_impl_mockchain::certificate;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{fmt, str::FromStr};
@@ -16,6 +18,17 @@ pub struct Certificate(pub certificate::Certificate);
 #[derive(Debug, Clone)]
 pub struct SignedCertificate(pub certificate::SignedCertificate);
 
+/// public data of a stake pool registration certificate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolRegistrationInfo {
+    pub pool_id: certificate::PoolId,
+    pub owners: Vec<Identifier<Ed25519>>,
+    pub operators: Vec<Identifier<Ed25519>>,
+    pub kes_public_key: Identifier<SumEd25519_12>,
+    pub vrf_public_key: Identifier<Curve25519_2HashDH>,
+    pub management_threshold: u8,
+}
+
 impl PartialEq for SignedCertificate {
     fn eq(&self, other: &Self) -> bool {
         use property::Serialize as _;
@@ -255,6 +268,33 @@ pub enum CertificateFromStrError {
 }
 
 impl Certificate {
+    /// `None` if the certificate is not a stake pool registration
+    pub fn pool_registration_info(&self) -> Option<PoolRegistrationInfo> {
+        match &self.0 {
+            certificate::Certificate::PoolRegistration(registration) => {
+                Some(PoolRegistrationInfo {
+                    pool_id: registration.to_id(),
+                    owners: registration
+                        .owners
+                        .iter()
+                        .cloned()
+                        .map(Into::into)
+                        .collect(),
+                    operators: registration
+                        .operators
+                        .iter()
+                        .cloned()
+                        .map(Into::into)
+                        .collect(),
+                    kes_public_key: registration.keys.kes_public_key.clone().into(),
+                    vrf_public_key: registration.keys.vrf_public_key.clone().into(),
+                    management_threshold: registration.management_threshold(),
+                })
+            }
+            _ => None,
+        }
+    }
+
     pub fn to_bech32(&self) -> Result<String, CertificateToBech32Error> {
         use chain_core::property::Serialize as _;
         let bytes = self.serialize_as_vec()?;
@@ -6,7 +6,7 @@ mod secret;
 pub use log::{Log, LogEntry, LogOutput};
 pub use mempool::{LogMaxEntries, Mempool, PoolMaxEntries};
 pub use node::{
-    Explorer, LayersConfig, NodeConfig, P2p, Policy, PreferredListConfig, Rest, TopicsOfInterest,
-    TrustedPeer,
+    AccountHistory, Explorer, LayersConfig, NodeConfig, P2p, Policy, PreferredListConfig, Rest,
+    TopicsOfInterest, TrustedPeer,
 };
 pub use secret::{Bft, GenesisPraos, NodeSecret};
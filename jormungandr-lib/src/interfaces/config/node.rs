@@ -58,6 +58,13 @@ pub struct Explorer {
     pub enabled: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct AccountHistory {
+    pub enabled: bool,
+    pub max_entries: usize,
+}
+
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct LayersConfig {
@@ -107,6 +114,8 @@ pub struct NodeConfig {
     pub p2p: P2p,
     pub log: Option<Log>,
     pub explorer: Explorer,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_history: Option<AccountHistory>,
     pub mempool: Option<Mempool>,
     pub bootstrap_from_trusted_peers: Option<bool>,
     pub skip_bootstrap: Option<bool>,
@@ -29,6 +29,22 @@ pub enum FragmentStatus {
     InABlock { date: BlockDate, block: Hash },
 }
 
+/// status of a fragment as reported for a single fragment, the block
+/// including it is located by its chain length as well
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FragmentStatusDto {
+    /// the fragment is yet to be processed
+    Pending,
+    /// the fragment has been rejected and won't be added in a block
+    Rejected { reason: String },
+    /// The fragment has been added in a block
+    InABlock {
+        date: BlockDate,
+        block: Hash,
+        chain_length: u32,
+    },
+}
+
 /// the log associated to a given fragment
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct FragmentLog {
@@ -1,3 +1,4 @@
+mod account_history;
 mod account_identifier;
 mod account_state;
 mod address;
@@ -27,6 +28,7 @@ mod utxo_info;
 mod value;
 mod vote;
 
+pub use self::account_history::AccountHistoryEntry;
 pub use self::account_identifier::AccountIdentifier;
 pub use self::account_state::AccountState;
 pub use self::address::Address;
@@ -34,11 +36,11 @@ pub use self::block0_configuration::*;
 pub use self::blockdate::BlockDate;
 pub use self::certificate::{
     Certificate, CertificateFromBech32Error, CertificateFromStrError, CertificateToBech32Error,
-    SignedCertificate, CERTIFICATE_HRP, SIGNED_CERTIFICATE_HRP,
+    PoolRegistrationInfo, SignedCertificate, CERTIFICATE_HRP, SIGNED_CERTIFICATE_HRP,
 };
 pub use self::committee::CommitteeIdDef;
 pub use self::config::*;
-pub use self::fragment_log::{FragmentLog, FragmentOrigin, FragmentStatus};
+pub use self::fragment_log::{FragmentLog, FragmentOrigin, FragmentStatus, FragmentStatusDto};
 pub use self::leadership_log::{
     EnclaveLeaderId, LeadershipLog, LeadershipLogId, LeadershipLogStatus,
 };
@@ -61,4 +63,4 @@ pub use self::transaction_output::TransactionOutput;
 pub use self::transaction_witness::TransactionWitness;
 pub use self::utxo_info::{UTxOInfo, UTxOOutputInfo};
 pub use self::value::{Value, ValueDef};
-pub use self::vote::{VotePlanSerializableHelper, VotePlanWithId};
+pub use self::vote::{ProposalWithIndex, VotePlanSerializableHelper, VotePlanWithId};
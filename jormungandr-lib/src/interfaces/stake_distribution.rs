@@ -1,6 +1,7 @@
 use crate::crypto::hash::Hash;
 use crate::interfaces::stake::Stake;
 use serde::{Deserialize, Serialize};
+use std::io::{self, Write};
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -16,3 +17,40 @@ pub struct StakeDistribution {
     pub unassigned: Stake,
     pub pools: Vec<(Hash, Stake)>,
 }
+
+impl StakeDistribution {
+    /// write the stake of every pool as `pool_id,stake_value` rows, sorted
+    /// by pool id, followed by a final `dangling,<value>` row
+    pub fn to_csv<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let mut pools: Vec<&(Hash, Stake)> = self.pools.iter().collect();
+        pools.sort_by_key(|(pool_id, _)| *pool_id);
+        for (pool_id, stake) in pools {
+            writeln!(w, "{},{}", pool_id, stake)?;
+        }
+        writeln!(w, "dangling,{}", self.dangling)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn to_csv_sorts_pools_and_ends_with_dangling() {
+        let first = Hash::from([1; 32]);
+        let second = Hash::from([2; 32]);
+        let distribution = StakeDistribution {
+            dangling: 30.into(),
+            unassigned: 0.into(),
+            pools: vec![(second, 200.into()), (first, 100.into())],
+        };
+
+        let mut csv = Vec::new();
+        distribution.to_csv(&mut csv).unwrap();
+
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            format!("{},100\n{},200\ndangling,30\n", first, second)
+        );
+    }
+}
@@ -1,8 +1,8 @@
 use crate::{crypto::hash::Hash, interfaces::blockdate::BlockDateDef};
 use core::ops::Range;
-use serde::{ser::SerializeSeq, Serialize, Serializer};
+use serde::{ser::SerializeSeq, Deserialize, Serialize, Serializer};
 
-#[derive(Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct VoteOptions {
     range: Range<u8>,
 }
@@ -33,7 +33,7 @@ pub struct ProposalSerializableHelper<'a>(
     #[serde(with = "Proposal")] pub &'a chain_impl_mockchain::certificate::Proposal,
 );
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[serde(remote = "chain_impl_mockchain::vote::PayloadType")]
 pub enum PayloadTypeDef {
     Public,
@@ -98,7 +98,7 @@ impl VotePlanSerializableHelper {
     }
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VotePlanWithId {
     pub voteplan_id: Hash,
 
@@ -114,8 +114,7 @@ pub struct VotePlanWithId {
     #[serde(with = "PayloadTypeDef")]
     pub payload_type: chain_impl_mockchain::vote::PayloadType,
 
-    #[serde(serialize_with = "serialize_proposals_index")]
-    pub proposals: chain_impl_mockchain::certificate::Proposals,
+    pub proposals: Vec<ProposalWithIndex>,
 }
 
 impl VotePlanWithId {
@@ -126,32 +125,23 @@ impl VotePlanWithId {
             vote_end: vote_plan.vote_end(),
             committee_end: vote_plan.committee_end(),
             payload_type: vote_plan.payload_type(),
-            proposals: vote_plan.proposals().clone(),
+            proposals: vote_plan
+                .proposals()
+                .iter()
+                .enumerate()
+                .map(|(i, e)| ProposalWithIndex {
+                    external_id: get_proposal_hash(e),
+                    options: get_proposal_vote_options(e),
+                    index: i as u8,
+                })
+                .collect(),
         }
     }
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ProposalWithIndex {
     pub external_id: Hash,
     pub options: VoteOptions,
     pub index: u8,
 }
-
-fn serialize_proposals_index<S>(
-    proposals: &chain_impl_mockchain::certificate::Proposals,
-    serializer: S,
-) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    let mut seq = serializer.serialize_seq(Some(proposals.len()))?;
-    for (i, e) in proposals.iter().enumerate() {
-        seq.serialize_element(&ProposalWithIndex {
-            external_id: get_proposal_hash(e),
-            options: get_proposal_vote_options(e),
-            index: i as u8,
-        })?;
-    }
-    seq.end()
-}
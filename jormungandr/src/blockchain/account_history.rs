@@ -0,0 +1,151 @@
+use crate::blockcfg::{Block, ChainLength, Fragment, FragmentId};
+use chain_addr::Kind;
+use chain_core::property::Fragment as _;
+use chain_impl_mockchain::{
+    account::Identifier,
+    transaction::{InputEnum, TransactionSlice},
+};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+};
+use tokio::sync::RwLock;
+
+/// index of the fragments touching each account, either as an input or as
+/// an output, filled as the blocks are applied to the blockchain.
+///
+/// Only the `max_entries` most recent entries are kept for every account.
+/// The blocks are indexed in the order they are applied, so entries from
+/// blocks of branches that ended up being abandoned are kept too.
+#[derive(Clone)]
+pub struct AccountHistoryIndex {
+    max_entries: usize,
+    entries: Arc<RwLock<HashMap<Identifier, VecDeque<(ChainLength, FragmentId)>>>>,
+}
+
+impl AccountHistoryIndex {
+    pub fn new(max_entries: usize) -> Self {
+        AccountHistoryIndex {
+            max_entries,
+            entries: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    pub async fn apply_block(&self, block: &Block) {
+        self.apply_fragments(block.header.chain_length(), block.fragments())
+            .await
+    }
+
+    pub async fn apply_fragments<'a>(
+        &self,
+        chain_length: ChainLength,
+        fragments: impl Iterator<Item = &'a Fragment>,
+    ) {
+        let mut entries = self.entries.write().await;
+        for fragment in fragments {
+            let fragment_id = fragment.id();
+            for account in touched_accounts(fragment) {
+                let history = entries.entry(account).or_insert_with(VecDeque::new);
+                history.push_back((chain_length, fragment_id));
+                if history.len() > self.max_entries {
+                    history.pop_front();
+                }
+            }
+        }
+    }
+
+    /// the fragments touching the given account, from the oldest to the
+    /// newest, along with the chain length of the block including them
+    pub async fn get(&self, account: &Identifier) -> Vec<(ChainLength, FragmentId)> {
+        self.entries
+            .read()
+            .await
+            .get(account)
+            .map(|history| history.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+fn touched_accounts(fragment: &Fragment) -> Vec<Identifier> {
+    let mut accounts = match fragment {
+        Fragment::Transaction(tx) => transaction_accounts(&tx.as_slice()),
+        Fragment::OwnerStakeDelegation(tx) => transaction_accounts(&tx.as_slice()),
+        Fragment::StakeDelegation(tx) => transaction_accounts(&tx.as_slice()),
+        Fragment::PoolRegistration(tx) => transaction_accounts(&tx.as_slice()),
+        Fragment::PoolRetirement(tx) => transaction_accounts(&tx.as_slice()),
+        Fragment::PoolUpdate(tx) => transaction_accounts(&tx.as_slice()),
+        Fragment::VotePlan(tx) => transaction_accounts(&tx.as_slice()),
+        Fragment::VoteCast(tx) => transaction_accounts(&tx.as_slice()),
+        _ => Vec::new(),
+    };
+    // an account spending to itself is only recorded once per fragment
+    accounts.sort();
+    accounts.dedup();
+    accounts
+}
+
+fn transaction_accounts<P>(tx: &TransactionSlice<P>) -> Vec<Identifier> {
+    let inputs = tx
+        .inputs()
+        .iter()
+        .filter_map(|input| match input.to_enum() {
+            InputEnum::AccountInput(id, _) => id.to_single_account(),
+            InputEnum::UtxoInput(_) => None,
+        });
+    let outputs = tx
+        .outputs()
+        .iter()
+        .filter_map(|output| match output.address.kind() {
+            Kind::Account(key) => Some(key.clone().into()),
+            _ => None,
+        });
+    inputs.chain(outputs).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chain_addr::{Address, Discrimination};
+    use chain_crypto::{Ed25519, PublicKey, SecretKey};
+    use chain_impl_mockchain::{
+        transaction::{Output, TxBuilder},
+        value::Value,
+    };
+
+    fn new_account() -> PublicKey<Ed25519> {
+        SecretKey::<Ed25519>::generate(rand_core::OsRng).to_public()
+    }
+
+    fn funding_fragment(account: &PublicKey<Ed25519>, value: u64) -> Fragment {
+        let address = Address(Discrimination::Test, Kind::Account(account.clone()));
+        let tx = TxBuilder::new()
+            .set_nopayload()
+            .set_ios(&[], &[Output::from_address(address, Value(value))])
+            .set_witnesses(&[])
+            .set_payload_auth(&());
+        Fragment::Transaction(tx)
+    }
+
+    #[tokio::test]
+    async fn account_history_keeps_entries_in_order() {
+        let account = new_account();
+        let first = funding_fragment(&account, 100);
+        let second = funding_fragment(&account, 200);
+        let index = AccountHistoryIndex::new(16);
+
+        index
+            .apply_fragments(ChainLength::from(1), std::iter::once(&first))
+            .await;
+        index
+            .apply_fragments(ChainLength::from(2), std::iter::once(&second))
+            .await;
+
+        assert_eq!(
+            index.get(&account.into()).await,
+            vec![
+                (ChainLength::from(1), first.id()),
+                (ChainLength::from(2), second.id()),
+            ]
+        );
+    }
+}
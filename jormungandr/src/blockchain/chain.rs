@@ -49,7 +49,7 @@ See Internal documentation for more details: doc/internal_design.md
 [`Branch`]: ./struct.Branch.html
 */
 
-use super::{branch::Branches, reference_cache::RefCache};
+use super::{account_history::AccountHistoryIndex, branch::Branches, reference_cache::RefCache};
 use crate::{
     blockcfg::{
         Block, Block0Error, BlockDate, ChainLength, Epoch, EpochRewardsInfo, Header, HeaderHash,
@@ -130,6 +130,10 @@ pub enum HeaderChainVerifyError {
 
 pub const MAIN_BRANCH_TAG: &str = "HEAD";
 
+/// the replayed branch only ever looks up the parent of the block being
+/// applied, there is no need for a large cache
+const LEDGER_REPLAY_CACHE_CAPACITY: usize = 16;
+
 /// Performs lightweight sanity checks on information fields of a block header
 /// against those in the header of the block's parent.
 /// The `parent` header must have been retrieved based on, or otherwise
@@ -184,6 +188,8 @@ pub struct Blockchain {
     block0: HeaderHash,
 
     rewards_report_all: bool,
+
+    account_history: Option<AccountHistoryIndex>,
 }
 
 pub enum PreCheckedHeader {
@@ -271,6 +277,7 @@ impl Blockchain {
         storage: Storage,
         cache_capacity: usize,
         rewards_report_all: bool,
+        account_history: Option<AccountHistoryIndex>,
     ) -> Self {
         Blockchain {
             branches: Branches::new(),
@@ -279,6 +286,7 @@ impl Blockchain {
             storage,
             block0,
             rewards_report_all,
+            account_history,
         }
     }
 
@@ -290,6 +298,16 @@ impl Blockchain {
         &self.storage
     }
 
+    pub fn account_history(&self) -> Option<&AccountHistoryIndex> {
+        self.account_history.as_ref()
+    }
+
+    async fn index_account_history(&self, block: &Block) {
+        if let Some(account_history) = &self.account_history {
+            account_history.apply_block(block).await
+        }
+    }
+
     pub fn branches(&self) -> &Branches {
         &self.branches
     }
@@ -510,11 +528,15 @@ impl Blockchain {
         block: Block,
     ) -> Result<AppliedBlock> {
         let new_ledger = self.apply_block_dry_run(&post_checked_header, &block)?;
+        let block_for_account_history = self.account_history.as_ref().map(|_| block.clone());
 
         let res = self.storage.put_block(block).await;
 
         match res {
             Ok(()) | Err(StorageError::BlockAlreadyPresent) => {
+                if let (Ok(()), Some(block)) = (&res, block_for_account_history) {
+                    self.index_account_history(&block).await;
+                }
                 let block_ref = self
                     .apply_block_finalize(post_checked_header, new_ledger)
                     .await;
@@ -585,6 +607,7 @@ impl Blockchain {
             .await;
         let b = Branch::new(b);
         branches.add(b.clone()).await;
+        self.index_account_history(block0).await;
         Ok(b)
     }
 
@@ -646,6 +669,17 @@ impl Blockchain {
     /// * other errors while interacting with the storage (IO errors)
     ///
     pub async fn load_from_storage(&self, block0: Block, logger: &Logger) -> Result<Branch> {
+        self.load_from_storage_until(block0, None, logger).await
+    }
+
+    /// same as `load_from_storage` but stops replaying the blocks of the
+    /// main branch once the given chain length has been reached.
+    async fn load_from_storage_until(
+        &self,
+        block0: Block,
+        until: Option<ChainLength>,
+        logger: &Logger,
+    ) -> Result<Branch> {
         let block0_id = block0.header.hash();
         let already_exist = self
             .storage
@@ -694,6 +728,10 @@ impl Blockchain {
                 Ok(block) => {
                     let header = block.header.clone();
 
+                    if until.map_or(false, |until| header.chain_length() > until) {
+                        break;
+                    }
+
                     const PROCESS_LOGGING_DISTANCE: u64 = 2500;
                     if count % PROCESS_LOGGING_DISTANCE == 0 {
                         info!(
@@ -735,6 +773,7 @@ impl Blockchain {
                     };
 
                     let new_ledger = self.apply_block_dry_run(&post_checked_header, &block)?;
+                    self.index_account_history(&block).await;
                     let new_ref = self
                         .apply_block_finalize(post_checked_header, new_ledger)
                         .await;
@@ -753,6 +792,44 @@ impl Blockchain {
         Ok(branch)
     }
 
+    /// rebuild the ledger state of the main branch as it was right after
+    /// applying the block at the given chain length.
+    ///
+    /// The blocks are replayed from the block0 found in the storage in a
+    /// separate `Blockchain`, so the caches of this one are left untouched.
+    ///
+    /// Returns `None` if the main branch has not reached this chain length.
+    pub async fn ledger_at_chain_length(
+        &self,
+        chain_length: ChainLength,
+        logger: &Logger,
+    ) -> Result<Option<Arc<Ledger>>> {
+        let block0 = self
+            .storage
+            .get(self.block0.clone())
+            .await
+            .map_err(|e| Error::with_chain(e, "Cannot get block0 from the storage"))?
+            .ok_or_else(|| Error::from(ErrorKind::Block0NotAlreadyInStorage))?;
+
+        let replay = Blockchain::new(
+            self.block0.clone(),
+            self.storage.clone(),
+            LEDGER_REPLAY_CACHE_CAPACITY,
+            self.rewards_report_all,
+            None,
+        );
+        let branch = replay
+            .load_from_storage_until(block0, Some(chain_length), logger)
+            .await?;
+        let reference = branch.get_ref().await;
+
+        if reference.chain_length() == chain_length {
+            Ok(Some(reference.ledger()))
+        } else {
+            Ok(None)
+        }
+    }
+
     pub async fn get_checkpoints(&self, branch: &Branch) -> Checkpoints {
         Checkpoints::new_from(branch.get_ref().await)
     }
@@ -891,3 +968,97 @@ pub fn new_epoch_leadership_from(
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        blockcfg::{BlockVersion, Contents, HeaderBuilderNew},
+        start_up,
+    };
+    use chain_addr::Discrimination;
+    use chain_crypto::{Ed25519, SecretKey};
+    use chain_impl_mockchain::{chaintypes::ConsensusVersion, fee::LinearFee, key::BftLeaderId};
+    use chain_storage::BlockStoreBuilder;
+    use jormungandr_lib::interfaces::{
+        Block0Configuration, BlockchainConfiguration, ConsensusLeaderId,
+    };
+    use slog::Discard;
+
+    fn block0(leader: &SecretKey<Ed25519>) -> Block {
+        let mut blockchain_configuration = BlockchainConfiguration::new(
+            Discrimination::Test,
+            ConsensusVersion::Bft,
+            LinearFee::new(0, 0, 0),
+        );
+        blockchain_configuration.consensus_leader_ids =
+            vec![ConsensusLeaderId(BftLeaderId::from(leader.to_public()))];
+        Block0Configuration {
+            blockchain_configuration,
+            initial: Vec::new(),
+        }
+        .to_block()
+    }
+
+    fn empty_block(leader: &SecretKey<Ed25519>, parent: &Header) -> Block {
+        let contents = Contents::empty();
+        let chain_length = parent.chain_length().increase();
+        let parent_date = parent.block_date();
+        let date = BlockDate {
+            epoch: parent_date.epoch,
+            slot_id: parent_date.slot_id + 1,
+        };
+        let builder = HeaderBuilderNew::new(BlockVersion::Ed25519Signed, &contents)
+            .set_parent(&parent.hash(), chain_length)
+            .set_date(date)
+            .into_bft_builder()
+            .unwrap()
+            .set_consensus_data(&BftLeaderId::from(leader.to_public()));
+        let signature = leader.sign_slice(builder.get_authenticated_data());
+        let header = builder.set_signature(signature.into()).generalize();
+        Block { header, contents }
+    }
+
+    #[tokio::test]
+    async fn ledger_at_chain_length_replays_the_main_branch() {
+        let logger = Logger::root(Discard, o!());
+        let leader = SecretKey::<Ed25519>::generate(rand_core::OsRng);
+        let block0 = block0(&leader);
+        let storage = Storage::new(BlockStoreBuilder::memory().build(), logger.clone());
+        let (blockchain, _tip) =
+            start_up::load_blockchain(block0.clone(), storage.clone(), 16, false, None, &logger)
+                .await
+                .unwrap();
+
+        let mut parent = block0.header;
+        for _ in 0..5 {
+            let block = empty_block(&leader, &parent);
+            parent = block.header.clone();
+            storage.put_block(block).await.unwrap();
+        }
+        storage
+            .put_tag(MAIN_BRANCH_TAG.to_owned(), parent.hash())
+            .await
+            .unwrap();
+
+        let at_height_3 = blockchain
+            .ledger_at_chain_length(ChainLength::from(3), &logger)
+            .await
+            .unwrap()
+            .unwrap();
+        let at_tip = blockchain
+            .ledger_at_chain_length(ChainLength::from(5), &logger)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(at_height_3.chain_length(), ChainLength::from(3));
+        assert_eq!(at_tip.chain_length(), ChainLength::from(5));
+        assert_ne!(at_height_3.date(), at_tip.date());
+        assert!(blockchain
+            .ledger_at_chain_length(ChainLength::from(6), &logger)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -20,7 +20,7 @@ extern crate slog_syslog;
 
 use crate::{
     blockcfg::{HeaderHash, Leader},
-    blockchain::Blockchain,
+    blockchain::{AccountHistoryIndex, Blockchain},
     diagnostic::Diagnostic,
     secure::enclave::Enclave,
     settings::start::Settings,
@@ -392,6 +392,7 @@ async fn bootstrap_internal(
         storage,
         cache_capacity,
         settings.rewards_report_all,
+        settings.account_history.map(AccountHistoryIndex::new),
         &logger,
     )
     .await?;
@@ -541,10 +542,10 @@ fn initialize_node() -> Result<InitializedNode, start_up::Error> {
 
             let service_context = context.clone();
             let explorer = settings.explorer;
-            let server_handler = rest::start_rest_server(rest, explorer, context.clone());
             services.spawn_future("rest", move |info| async move {
-                service_context.write().await.set_logger(info.into_logger());
-                server_handler.await
+                let logger = info.into_logger();
+                service_context.write().await.set_logger(logger.clone());
+                rest::start_rest_server(rest, explorer, service_context, logger).await
             });
             Some(context)
         }
@@ -9,6 +9,7 @@ pub use self::context::{Context, ContextLock, FullContext};
 use crate::settings::start::{Rest, Tls};
 
 use futures::{channel::mpsc, prelude::*};
+use slog::Logger;
 use std::{error::Error, net::SocketAddr, time::Duration};
 use warp::Filter;
 
@@ -21,7 +22,20 @@ impl ServerStopper {
     }
 }
 
-pub async fn start_rest_server(config: Rest, explorer_enabled: bool, context: ContextLock) {
+pub async fn start_rest_server(
+    mut config: Rest,
+    explorer_enabled: bool,
+    context: ContextLock,
+    logger: Logger,
+) {
+    if config.insecure_disable_tls && config.tls.is_some() {
+        warn!(
+            logger,
+            "TLS is configured but disabled by `insecure_disable_tls`, serving plain HTTP"
+        );
+        config.tls = None;
+    }
+
     let (stopper_tx, stopper_rx) = mpsc::channel::<()>(0);
     let stopper_rx = stopper_rx.into_future().map(|_| ());
     context
@@ -104,3 +118,57 @@ pub(self) fn display_internal_server_error(err: &impl Error) -> String {
 
     error_to_body(err).unwrap_or_else(|err| format!("failed to process internal error: {}", err))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng as _;
+    use std::sync::{
+        atomic::{AtomicU16, Ordering},
+        Arc,
+    };
+    use tokio::sync::RwLock;
+
+    lazy_static! {
+        static ref NEXT_AVAILABLE_PORT_NUMBER: AtomicU16 = {
+            let initial_port = rand::thread_rng().gen_range(11000, 15999);
+            AtomicU16::new(initial_port)
+        };
+    }
+
+    fn get_available_port() -> u16 {
+        NEXT_AVAILABLE_PORT_NUMBER.fetch_add(1, Ordering::SeqCst)
+    }
+
+    #[tokio::test]
+    async fn insecure_disable_tls_serves_plain_http() {
+        let listen: SocketAddr = ([127, 0, 0, 1], get_available_port()).into();
+        // the TLS files do not exist, the server would fail to start if it
+        // tried to load them
+        let config = Rest {
+            listen,
+            tls: Some(Tls {
+                cert_file: "missing.crt".to_owned(),
+                priv_key_file: "missing.pk8".to_owned(),
+            }),
+            insecure_disable_tls: true,
+            cors: None,
+        };
+        let context = Arc::new(RwLock::new(Context::new()));
+        let logger = Logger::root(slog::Discard, o!());
+        tokio::spawn(start_rest_server(config, false, context.clone(), logger));
+
+        let url = format!("http://{}/api/v0/node/stats", listen);
+        let mut response = reqwest::get(&url).await;
+        for _ in 0..10 {
+            if response.is_ok() {
+                break;
+            }
+            tokio::time::delay_for(Duration::from_millis(100)).await;
+            response = reqwest::get(&url).await;
+        }
+
+        assert!(response.is_ok(), "no plain HTTP response from the server");
+        context.read().await.server_stopper().unwrap().stop();
+    }
+}
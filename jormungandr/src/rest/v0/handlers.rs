@@ -2,6 +2,7 @@ use crate::{
     rest::{v0::logic, ContextLock},
     secure::NodeSecret,
 };
+use jormungandr_lib::interfaces::Certificate;
 use warp::{reject::Reject, Rejection, Reply};
 
 impl Reject for logic::Error {}
@@ -18,6 +19,17 @@ pub async fn get_account_state(
         .ok_or(warp::reject::not_found())
 }
 
+pub async fn get_account_history(
+    account_id_hex: String,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::get_account_history(&context, &account_id_hex)
+        .await
+        .map(|r| warp::reply::json(&r))
+        .map_err(warp::reject::custom)
+}
+
 pub async fn get_message_logs(context: ContextLock) -> Result<impl Reply, Rejection> {
     let context = context.read().await;
     logic::get_message_logs(&context)
@@ -26,6 +38,18 @@ pub async fn get_message_logs(context: ContextLock) -> Result<impl Reply, Reject
         .map(|r| warp::reply::json(&r))
 }
 
+pub async fn get_fragment_status(
+    fragment_id_hex: String,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::get_fragment_status(&context, &fragment_id_hex)
+        .await
+        .map_err(warp::reject::custom)?
+        .map(|r| warp::reply::json(&r))
+        .ok_or(warp::reject::not_found())
+}
+
 pub async fn post_message(
     message: bytes::Bytes,
     context: ContextLock,
@@ -36,6 +60,25 @@ pub async fn post_message(
         .map_err(warp::reject::custom)
 }
 
+#[derive(Deserialize)]
+pub struct PostTransactionFeeBody {
+    inputs: u8,
+    outputs: u8,
+    #[serde(default)]
+    certificate: Option<Certificate>,
+}
+
+pub async fn post_transaction_fee(
+    body: PostTransactionFeeBody,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::get_transaction_fee(&context, body.inputs, body.outputs, body.certificate)
+        .await
+        .map(|r| warp::reply::json(&r))
+        .map_err(warp::reject::custom)
+}
+
 pub async fn get_tip(context: ContextLock) -> Result<impl Reply, Rejection> {
     let context = context.read().await;
     logic::get_tip(&context).await.map_err(warp::reject::custom)
@@ -106,6 +149,15 @@ pub async fn get_settings(context: ContextLock) -> Result<impl Reply, Rejection>
         .map_err(warp::reject::custom)
 }
 
+pub async fn get_block0_configuration(context: ContextLock) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::get_block0_configuration(&context)
+        .await
+        .map_err(warp::reject::custom)?
+        .map(|r| warp::reply::json(&r))
+        .ok_or(warp::reject::not_found())
+}
+
 pub async fn shutdown(context: ContextLock) -> Result<impl Reply, Rejection> {
     let mut context = context.write().await;
     logic::shutdown(&mut context)
@@ -150,6 +202,23 @@ pub async fn get_leaders_logs(context: ContextLock) -> Result<impl Reply, Reject
         .map_err(warp::reject::custom)
 }
 
+#[derive(Deserialize)]
+pub struct GetLeadersScheduleQuery {
+    pool_id: String,
+}
+
+pub async fn get_leaders_schedule(
+    query: GetLeadersScheduleQuery,
+    context: ContextLock,
+) -> Result<impl Reply, Rejection> {
+    let context = context.read().await;
+    logic::get_leaders_schedule(&context, &query.pool_id)
+        .await
+        .map_err(warp::reject::custom)?
+        .map(|r| warp::reply::json(&r))
+        .ok_or(warp::reject::not_found())
+}
+
 pub async fn get_stake_pools(context: ContextLock) -> Result<impl Reply, Rejection> {
     let context = context.read().await;
     logic::get_stake_pools(&context)
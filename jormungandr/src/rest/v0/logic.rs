@@ -23,6 +23,8 @@ use chain_crypto::{
 use chain_impl_mockchain::{
     account::{AccountAlg, Identifier},
     block::Block as ChainBlock,
+    certificate::{CertificatePayload, PoolId},
+    fee::FeeAlgorithm,
     fragment::{Fragment, FragmentId},
     key::Hash,
     leadership::{Leader, LeadershipConsensus},
@@ -31,10 +33,11 @@ use chain_impl_mockchain::{
 };
 use jormungandr_lib::{
     interfaces::{
-        AccountState, EnclaveLeaderId, EpochRewardsInfo, FragmentLog, FragmentOrigin,
-        LeadershipLog, NodeStats, NodeStatsDto, PeerStats, Rewards as StakePoolRewards,
-        SettingsDto, StakeDistribution, StakeDistributionDto, StakePoolStats, TaxTypeSerde,
-        TransactionOutput, VotePlanWithId,
+        AccountHistoryEntry, AccountState, Block0Configuration, Block0ConfigurationError,
+        BlockDate, BlockchainConfiguration, Certificate, EnclaveLeaderId, EpochRewardsInfo,
+        FragmentLog, FragmentOrigin, FragmentStatus, FragmentStatusDto, LeadershipLog, NodeStats,
+        NodeStatsDto, PeerStats, Rewards as StakePoolRewards, SettingsDto, StakeDistribution,
+        StakeDistributionDto, StakePoolStats, TaxTypeSerde, TransactionOutput, VotePlanWithId,
     },
     time::SystemTime,
 };
@@ -63,6 +66,8 @@ pub enum Error {
     Value(#[from] ValueError),
     #[error("Could not find block for tip")]
     TipBlockNotFound,
+    #[error("Could not find the block including the fragment")]
+    FragmentBlockNotFound,
     #[error(transparent)]
     Hash(#[from] HashError),
     #[error(transparent)]
@@ -71,6 +76,10 @@ pub enum Error {
     Storage(#[from] StorageError),
     #[error("Invalid topic")]
     InvalidTopic,
+    #[error("Block0 cannot be decoded into a configuration")]
+    Block0Configuration(#[from] Block0ConfigurationError),
+    #[error("Account history is not enabled")]
+    AccountHistoryNotEnabled,
 }
 
 fn parse_account_id(id_hex: &str) -> Result<Identifier, Error> {
@@ -107,6 +116,25 @@ pub async fn get_account_state(
         .map(Into::into))
 }
 
+pub async fn get_account_history(
+    context: &Context,
+    account_id_hex: &str,
+) -> Result<Vec<AccountHistoryEntry>, Error> {
+    let account_history = context
+        .blockchain()?
+        .account_history()
+        .ok_or(Error::AccountHistoryNotEnabled)?;
+    Ok(account_history
+        .get(&parse_account_id(account_id_hex)?)
+        .await
+        .into_iter()
+        .map(|(chain_length, fragment_id)| AccountHistoryEntry {
+            fragment_id: fragment_id.into(),
+            chain_length: chain_length.into(),
+        })
+        .collect())
+}
+
 pub async fn get_message_logs(context: &Context) -> Result<Vec<FragmentLog>, Error> {
     let logger = context.logger()?.new(o!("request" => "message_logs"));
     let (reply_handle, reply_future) = intercom::unary_reply(logger.clone());
@@ -120,6 +148,44 @@ pub async fn get_message_logs(context: &Context) -> Result<Vec<FragmentLog>, Err
     reply_future.await.map_err(Into::into)
 }
 
+/// status of the given fragment in the message pool logs, along with the
+/// chain length of the block including it, `None` if the fragment is
+/// unknown or its log already expired
+pub async fn get_fragment_status(
+    context: &Context,
+    fragment_id_hex: &str,
+) -> Result<Option<FragmentStatusDto>, Error> {
+    let fragment_id = parse_fragment_id(fragment_id_hex)?;
+    let status = match get_message_logs(context)
+        .await?
+        .into_iter()
+        .find(|log| log.fragment_id().clone().into_hash() == fragment_id)
+    {
+        Some(log) => log.status().clone(),
+        None => return Ok(None),
+    };
+    let status = match status {
+        FragmentStatus::Pending => FragmentStatusDto::Pending,
+        FragmentStatus::Rejected { reason } => FragmentStatusDto::Rejected { reason },
+        FragmentStatus::InABlock { date, block } => {
+            let chain_length = context
+                .blockchain()?
+                .storage()
+                .get(block.into_hash())
+                .await?
+                .ok_or(Error::FragmentBlockNotFound)?
+                .header
+                .chain_length();
+            FragmentStatusDto::InABlock {
+                date,
+                block,
+                chain_length: chain_length.into(),
+            }
+        }
+    };
+    Ok(Some(status))
+}
+
 pub async fn post_message(context: &Context, message: &[u8]) -> Result<String, Error> {
     let fragment = Fragment::deserialize(message).map_err(Error::Deserialize)?;
     let fragment_id = fragment.id().to_string();
@@ -128,6 +194,30 @@ pub async fn post_message(context: &Context, message: &[u8]) -> Result<String, E
     Ok(fragment_id)
 }
 
+/// fee of a transaction with the given number of inputs and outputs, and
+/// optionally carrying the given certificate, according to the linear fee
+/// settings of the current tip
+pub async fn get_transaction_fee(
+    context: &Context,
+    inputs: u8,
+    outputs: u8,
+    certificate: Option<Certificate>,
+) -> Result<jormungandr_lib::interfaces::Value, Error> {
+    let fees = context
+        .blockchain_tip()?
+        .get_ref()
+        .await
+        .epoch_ledger_parameters()
+        .fees;
+    let payload = certificate.map(|certificate| CertificatePayload::from(&certificate.0));
+    let fee = fees.calculate(
+        payload.as_ref().map(CertificatePayload::as_slice),
+        inputs,
+        outputs,
+    );
+    Ok(fee.into())
+}
+
 pub async fn get_tip(context: &Context) -> Result<String, Error> {
     Ok(context.blockchain_tip()?.get_ref().await.hash().to_string())
 }
@@ -375,6 +465,22 @@ pub async fn get_settings(context: &Context) -> Result<SettingsDto, Error> {
     })
 }
 
+pub async fn get_block0_configuration(
+    context: &Context,
+) -> Result<Option<BlockchainConfiguration>, Error> {
+    let blockchain = context.blockchain()?;
+    blockchain
+        .storage()
+        .get(blockchain.block0().clone())
+        .await?
+        .map(|block0| {
+            Block0Configuration::from_block(&block0)
+                .map(|config| config.blockchain_configuration)
+                .map_err(Into::into)
+        })
+        .transpose()
+}
+
 pub async fn shutdown(context: &mut Context) -> Result<(), Error> {
     context.stop_bootstrap();
     context.server_stopper()?.stop();
@@ -411,6 +517,27 @@ pub async fn get_leaders_logs(context: &Context) -> Result<Vec<LeadershipLog>, E
     Ok(context.try_full()?.leadership_logs.logs().await)
 }
 
+pub async fn get_leaders_schedule(
+    context: &Context,
+    pool_id_hex: &str,
+) -> Result<Option<Vec<BlockDate>>, Error> {
+    let pool_id: PoolId = pool_id_hex.parse()?;
+    let leadership = context
+        .blockchain_tip()?
+        .get_ref()
+        .await
+        .epoch_leadership_schedule()
+        .clone();
+    let nb_slots = leadership.era().slots_per_epoch();
+    let schedule = context
+        .try_full()?
+        .enclave
+        .pool_leadership_evaluate(&leadership, &pool_id, 0, nb_slots)
+        .await
+        .map(|events| events.into_iter().map(|event| event.date.into()).collect());
+    Ok(schedule)
+}
+
 pub async fn get_stake_pools(context: &Context) -> Result<Vec<String>, Error> {
     Ok(context
         .blockchain_tip()?
@@ -646,3 +773,96 @@ pub async fn get_active_vote_plans(context: &Context) -> Result<Vec<VotePlanWith
         .collect();
     Ok(vp)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rest::v0::mock::MockBlockchain;
+    use chain_addr::{Address, Discrimination, Kind};
+    use chain_crypto::{Ed25519, SecretKey};
+    use chain_impl_mockchain::{
+        account::DelegationType,
+        certificate::{self, OwnerStakeDelegation, StakeDelegation},
+        fee::{LinearFee, PerCertificateFee},
+        transaction::UnspecifiedAccountIdentifier,
+    };
+    use jormungandr_lib::interfaces::InitialUTxO;
+    use std::num::NonZeroU64;
+
+    fn new_utxo(value: u64) -> InitialUTxO {
+        let public_key = SecretKey::<Ed25519>::generate(rand_core::OsRng).to_public();
+        InitialUTxO {
+            address: Address(Discrimination::Test, Kind::Single(public_key)).into(),
+            value: value.into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_utxo_returns_block0_outputs() {
+        let values = [100, 200];
+        let utxos: Vec<_> = values.iter().map(|value| new_utxo(*value)).collect();
+        let mock = MockBlockchain::new().with_utxos(utxos.clone());
+        let fragment_id = mock
+            .block0()
+            .fragments()
+            .find_map(|fragment| match fragment {
+                Fragment::Transaction(_) => Some(fragment.id()),
+                _ => None,
+            })
+            .expect("block0 has no initial funds")
+            .to_string();
+        let context = mock.build().await;
+
+        for (index, (utxo, value)) in utxos.iter().zip(values.iter()).enumerate() {
+            let output = get_utxo(&context, &fragment_id, index as u8)
+                .await
+                .unwrap()
+                .expect("utxo not found");
+            assert_eq!(
+                serde_json::to_value(&output).unwrap(),
+                serde_json::json!({
+                    "address": utxo.address.to_string(),
+                    "value": value,
+                })
+            );
+        }
+
+        assert!(get_utxo(&context, &fragment_id, utxos.len() as u8)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn get_transaction_fee_follows_linear_fee() {
+        let mut linear_fee = LinearFee::new(10, 2, 5);
+        linear_fee.per_certificate_fees(PerCertificateFee::new(None, NonZeroU64::new(7), None));
+        let context = MockBlockchain::new()
+            .with_linear_fees(linear_fee)
+            .build()
+            .await;
+
+        let fee = get_transaction_fee(&context, 2, 2, None).await.unwrap();
+        assert_eq!(fee, Value(10 + 2 * 4).into());
+
+        let account = SecretKey::<Ed25519>::generate(rand_core::OsRng).to_public();
+        let delegation = Certificate(certificate::Certificate::StakeDelegation(StakeDelegation {
+            account_id: UnspecifiedAccountIdentifier::from_single_account(account.into()),
+            delegation: DelegationType::NonDelegated,
+        }));
+        let fee = get_transaction_fee(&context, 2, 2, Some(delegation))
+            .await
+            .unwrap();
+        assert_eq!(fee, Value(10 + 2 * 4 + 7).into());
+
+        let owner_delegation = Certificate(certificate::Certificate::OwnerStakeDelegation(
+            OwnerStakeDelegation {
+                delegation: DelegationType::NonDelegated,
+            },
+        ));
+        let fee = get_transaction_fee(&context, 2, 2, Some(owner_delegation))
+            .await
+            .unwrap();
+        assert_eq!(fee, Value(10 + 2 * 4 + 5).into());
+    }
+}
@@ -0,0 +1,73 @@
+//! In-memory blockchain to drive the REST logic from unit tests, without
+//! starting a full node.
+
+use crate::{blockcfg::Block, blockchain::Storage, rest::Context, start_up};
+use chain_addr::Discrimination;
+use chain_crypto::{Ed25519, SecretKey};
+use chain_impl_mockchain::{chaintypes::ConsensusVersion, fee::LinearFee, key::BftLeaderId};
+use chain_storage::BlockStoreBuilder;
+use jormungandr_lib::interfaces::{
+    Block0Configuration, BlockchainConfiguration, ConsensusLeaderId, Initial, InitialUTxO,
+};
+use slog::{Discard, Logger};
+
+const CACHE_CAPACITY: usize = 16;
+
+/// builder of a BFT blockchain loaded from a hand crafted block0 and stored
+/// in memory
+pub struct MockBlockchain {
+    block0_configuration: Block0Configuration,
+}
+
+impl MockBlockchain {
+    pub fn new() -> Self {
+        let leader = SecretKey::<Ed25519>::generate(rand_core::OsRng).to_public();
+        let mut blockchain_configuration = BlockchainConfiguration::new(
+            Discrimination::Test,
+            ConsensusVersion::Bft,
+            LinearFee::new(0, 0, 0),
+        );
+        blockchain_configuration.consensus_leader_ids =
+            vec![ConsensusLeaderId(BftLeaderId::from(leader))];
+
+        MockBlockchain {
+            block0_configuration: Block0Configuration {
+                blockchain_configuration,
+                initial: Vec::new(),
+            },
+        }
+    }
+
+    pub fn with_linear_fees(mut self, linear_fees: LinearFee) -> Self {
+        self.block0_configuration
+            .blockchain_configuration
+            .linear_fees = linear_fees;
+        self
+    }
+
+    pub fn with_utxos(mut self, utxos: Vec<InitialUTxO>) -> Self {
+        self.block0_configuration.initial.push(Initial::Fund(utxos));
+        self
+    }
+
+    pub fn block0(&self) -> Block {
+        self.block0_configuration.to_block()
+    }
+
+    /// load the block0 in a fresh in-memory storage and return a REST
+    /// context whose tip is the block0
+    pub async fn build(self) -> Context {
+        let logger = Logger::root(Discard, o!());
+        let storage = Storage::new(BlockStoreBuilder::memory().build(), logger.clone());
+        let (blockchain, tip) =
+            start_up::load_blockchain(self.block0(), storage, CACHE_CAPACITY, false, None, &logger)
+                .await
+                .expect("cannot load the mock blockchain");
+
+        let mut context = Context::new();
+        context.set_logger(logger);
+        context.set_blockchain(blockchain);
+        context.set_blockchain_tip(tip);
+        context
+    }
+}
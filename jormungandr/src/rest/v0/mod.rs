@@ -1,5 +1,7 @@
 mod handlers;
 pub mod logic;
+#[cfg(test)]
+mod mock;
 
 use crate::rest::{display_internal_server_error, ContextLock};
 
@@ -23,6 +25,12 @@ pub fn filter(
         .and_then(handlers::get_account_state)
         .boxed();
 
+    let account_history = warp::path!("account" / String / "history")
+        .and(warp::get())
+        .and(with_context.clone())
+        .and_then(handlers::get_account_history)
+        .boxed();
+
     let block = {
         let root = warp::path!("block" / ..);
 
@@ -42,11 +50,21 @@ pub fn filter(
         root.and(get.or(get_next)).boxed()
     };
 
-    let fragment = warp::path!("fragment" / "logs")
-        .and(warp::get())
-        .and(with_context.clone())
-        .and_then(handlers::get_message_logs)
-        .boxed();
+    let fragment = {
+        let logs = warp::path!("fragment" / "logs")
+            .and(warp::get())
+            .and(with_context.clone())
+            .and_then(handlers::get_message_logs)
+            .boxed();
+
+        let status = warp::path!("fragment" / String / "status")
+            .and(warp::get())
+            .and(with_context.clone())
+            .and_then(handlers::get_fragment_status)
+            .boxed();
+
+        logs.or(status).boxed()
+    };
 
     let leaders = {
         let root = warp::path!("leaders" / ..).boxed();
@@ -70,13 +88,21 @@ pub fn filter(
             .and_then(handlers::get_leaders_logs)
             .boxed();
 
+        let schedule = warp::path!("schedule")
+            .and(warp::get())
+            .and(warp::query())
+            .and(with_context.clone())
+            .and_then(handlers::get_leaders_schedule)
+            .boxed();
+
         let delete = warp::path!(u32)
             .and(warp::delete())
             .and(with_context.clone())
             .and_then(handlers::delete_leaders)
             .boxed();
 
-        root.and(get.or(post).or(logs).or(delete)).boxed()
+        root.and(get.or(post).or(logs).or(schedule).or(delete))
+            .boxed()
     };
 
     let p2p = {
@@ -134,11 +160,23 @@ pub fn filter(
         root.and(stats.or(p2p)).boxed()
     };
 
-    let settings = warp::path!("settings")
-        .and(warp::get())
-        .and(with_context.clone())
-        .and_then(handlers::get_settings)
-        .boxed();
+    let settings = {
+        let root = warp::path!("settings" / ..);
+
+        let get = warp::path::end()
+            .and(warp::get())
+            .and(with_context.clone())
+            .and_then(handlers::get_settings)
+            .boxed();
+
+        let block0 = warp::path!("block0")
+            .and(warp::get())
+            .and(with_context.clone())
+            .and_then(handlers::get_block0_configuration)
+            .boxed();
+
+        root.and(get.or(block0)).boxed()
+    };
 
     let stake = {
         let root = warp::path!("stake" / ..);
@@ -177,6 +215,13 @@ pub fn filter(
         .and_then(handlers::post_message)
         .boxed();
 
+    let transaction_fee = warp::path!("transaction" / "fee")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_context.clone())
+        .and_then(handlers::post_transaction_fee)
+        .boxed();
+
     let node_stats = warp::path!("node" / "stats")
         .and(warp::get())
         .and(with_context.clone())
@@ -237,6 +282,7 @@ pub fn filter(
 
     let routes = shutdown
         .or(account)
+        .or(account_history)
         .or(block)
         .or(fragment)
         .or(leaders)
@@ -246,6 +292,7 @@ pub fn filter(
         .or(stake_pools)
         .or(stake_pool)
         .or(message)
+        .or(transaction_fee)
         .or(node_stats)
         .or(tip)
         .or(rewards)
@@ -261,9 +308,9 @@ pub fn filter(
 async fn handle_rejection(err: Rejection) -> Result<impl Reply, Rejection> {
     if let Some(err) = err.find::<logic::Error>() {
         let (body, code) = match err {
-            logic::Error::PublicKey(_) | logic::Error::Hash(_) => {
-                (err.to_string(), StatusCode::BAD_REQUEST)
-            }
+            logic::Error::PublicKey(_)
+            | logic::Error::Hash(_)
+            | logic::Error::AccountHistoryNotEnabled => (err.to_string(), StatusCode::BAD_REQUEST),
             err => (
                 display_internal_server_error(err),
                 StatusCode::INTERNAL_SERVER_ERROR,
@@ -2,7 +2,10 @@ use crate::blockcfg::{
     BlockDate, HeaderBft, HeaderBftBuilder, HeaderGenesisPraos, HeaderGenesisPraosBuilder,
     HeaderSetConsensusSignature, SlotId,
 };
-use chain_impl_mockchain::leadership::{Leader, LeaderOutput, Leadership};
+use chain_impl_mockchain::{
+    certificate::PoolId,
+    leadership::{Leader, LeaderOutput, Leadership},
+};
 use jormungandr_lib::interfaces::EnclaveLeaderId as LeaderId;
 use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
@@ -169,6 +172,41 @@ impl Enclave {
         output
     }
 
+    /// same as `leadership_evaluate` for the genesis leader of `pool_id`
+    /// only, `None` if the keys of that pool are not in the enclave
+    pub async fn pool_leadership_evaluate(
+        &self,
+        leadership: &Leadership,
+        pool_id: &PoolId,
+        slot_start: u32,
+        nb_slots: u32,
+    ) -> Option<Vec<LeaderEvent>> {
+        let leaders = &self.leaders_data.read().await.leaders;
+        let (id, leader) = leaders.iter().find(|(_, leader)| {
+            leader
+                .genesis_leader
+                .as_ref()
+                .map_or(false, |genesis_leader| genesis_leader.node_id == *pool_id)
+        })?;
+
+        let mut output = Vec::new();
+        for slot_idx in slot_start..slot_start + nb_slots {
+            let date = leadership.date_at_slot(slot_idx);
+            match leadership.is_leader_for_date(&leader, date) {
+                Ok(LeaderOutput::None) => (),
+                Ok(leader_output) => output.push(LeaderEvent {
+                    id: *id,
+                    date,
+                    output: leader_output,
+                }),
+                Err(_) => {
+                    // For now silently ignore error
+                }
+            }
+        }
+        Some(output)
+    }
+
     pub async fn create_header_genesis_praos(
         &self,
         header_builder: HeaderGenesisPraosBuilder<HeaderSetConsensusSignature>,
@@ -35,6 +35,8 @@ pub struct Config {
 
     pub explorer: Option<Explorer>,
 
+    pub account_history: Option<AccountHistory>,
+
     /// the time interval with no blockchain updates after which alerts are thrown
     #[serde(default)]
     pub no_blockchain_updates_warning_interval: Option<Duration>,
@@ -61,6 +63,9 @@ pub struct Rest {
     pub listen: SocketAddr,
     /// Enables TLS and disables plain HTTP if provided
     pub tls: Option<Tls>,
+    /// Ignores `tls` and serves plain HTTP, for testing only
+    #[serde(default)]
+    pub insecure_disable_tls: bool,
     /// Enables CORS if provided
     pub cors: Option<Cors>,
 }
@@ -246,6 +251,14 @@ pub struct Explorer {
     pub enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct AccountHistory {
+    pub enabled: bool,
+    /// maximum number of fragments remembered for every account
+    pub max_entries: usize,
+}
+
 pub fn default_interests() -> BTreeMap<Topic, InterestLevel> {
     use std::iter::FromIterator as _;
 
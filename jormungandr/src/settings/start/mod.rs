@@ -39,6 +39,9 @@ pub struct Settings {
     pub rewards_report_all: bool,
     pub leadership: Leadership,
     pub explorer: bool,
+    /// maximum number of entries kept per account in the account history
+    /// index, `None` if the index is disabled
+    pub account_history: Option<usize>,
     pub no_blockchain_updates_warning_interval: std::time::Duration,
 }
 
@@ -108,6 +111,7 @@ impl RawSettings {
             (None, Some(cmd_listen)) => Some(Rest {
                 listen: cmd_listen,
                 tls: None,
+                insecure_disable_tls: false,
                 cors: None,
             }),
             (None, None) => None,
@@ -166,6 +170,12 @@ impl RawSettings {
                     .map_or(false, |settings| settings.enabled)
             });
 
+        let account_history = config
+            .as_ref()
+            .and_then(|cfg| cfg.account_history.as_ref())
+            .filter(|settings| settings.enabled)
+            .map(|settings| settings.max_entries);
+
         Ok(Settings {
             storage,
             block_0,
@@ -180,6 +190,7 @@ impl RawSettings {
                 .as_ref()
                 .map_or(Leadership::default(), |cfg| cfg.leadership.clone()),
             explorer,
+            account_history,
             no_blockchain_updates_warning_interval: config
                 .as_ref()
                 .and_then(|config| config.no_blockchain_updates_warning_interval.clone())
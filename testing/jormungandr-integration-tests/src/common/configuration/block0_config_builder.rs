@@ -8,7 +8,7 @@ extern crate rand_chacha;
 extern crate serde_derive;
 use self::chain_addr::{Address as ChainAddress, Discrimination, Kind};
 use self::chain_crypto::{Ed25519, Ed25519Extended, KeyPair, PublicKey, SecretKey};
-use self::rand::SeedableRng;
+use self::rand::{CryptoRng, RngCore, SeedableRng};
 use self::rand_chacha::ChaChaRng;
 use self::serde_derive::{Deserialize, Serialize};
 use chain_impl_mockchain::{chaintypes::ConsensusVersion, fee::LinearFee};
@@ -17,13 +17,19 @@ use jormungandr_lib::{
         ActiveSlotCoefficient, Block0Configuration, BlockchainConfiguration, CommitteeIdDef,
         ConsensusLeaderId, EpochStabilityDepth, Initial, InitialUTxO, KESUpdateSpeed,
         NumberOfSlotsPerEpoch, Ratio, RewardConstraints, RewardParams, SlotDuration, TaxType,
+        Value,
     },
     time::SecondsSinceUnixEpoch,
 };
+use jormungandr_testing_utils::wallet::Wallet;
 
 use std::num::NonZeroU32;
+use std::time::{Duration, SystemTime};
 use std::vec::Vec;
 
+/// a fund initial is a single transaction, which cannot hold more outputs
+const MAX_OUTPUTS_PER_FUND: usize = 254;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Block0ConfigurationBuilder {
     pub blockchain_configuration: BlockchainConfiguration,
@@ -75,14 +81,74 @@ impl Block0ConfigurationBuilder {
         }
     }
 
+    /// BFT block0 with a single leader and a single account wallet funded
+    /// with `value`, the wallet is returned to sign the test transactions
+    pub fn minimal_with_faucet(value: Value) -> (Block0Configuration, Wallet) {
+        let faucet = Wallet::new_account(&mut rand::rngs::OsRng);
+        let leader: KeyPair<Ed25519Extended> = KeyPair::generate(&mut rand::rngs::OsRng);
+        let block0_configuration = Self::new()
+            .with_bft_leaders_ordered(vec![leader.public_key().clone().into()])
+            .with_funds(vec![Initial::Fund(vec![InitialUTxO {
+                address: faucet.address(),
+                value,
+            }])])
+            .build();
+        (block0_configuration, faucet)
+    }
+
+    /// date the block0 `offset` in the past, so the blockchain starts
+    /// producing blocks right away instead of waiting for its genesis time
+    pub fn with_block0_date_offset(&mut self, offset: Duration) -> &mut Self {
+        self.blockchain_configuration.block0_date = (SystemTime::now() - offset).into();
+        self
+    }
+
+    pub fn with_discrimination(&mut self, discrimination: Discrimination) -> &mut Self {
+        self.blockchain_configuration.discrimination = discrimination;
+        self
+    }
+
     pub fn with_funds(&mut self, funds: Vec<Initial>) -> &mut Self {
         self.initial.extend(funds.iter().cloned());
         self
     }
+    /// fund `count` freshly generated addresses with `value_each`, spread
+    /// over as many fund initials as the outputs limit per fragment requires
+    pub fn with_utxo_distribution<RNG: RngCore + CryptoRng>(
+        &mut self,
+        count: usize,
+        value_each: Value,
+        rng: &mut RNG,
+    ) -> &mut Self {
+        let discrimination = self.blockchain_configuration.discrimination;
+        let utxos: Vec<InitialUTxO> = (0..count)
+            .map(|_| {
+                let secret_key: SecretKey<Ed25519Extended> = SecretKey::generate(&mut *rng);
+                InitialUTxO {
+                    address: ChainAddress(discrimination, Kind::Single(secret_key.to_public()))
+                        .into(),
+                    value: value_each,
+                }
+            })
+            .collect();
+        self.initial.extend(
+            utxos
+                .chunks(MAX_OUTPUTS_PER_FUND)
+                .map(|chunk| Initial::Fund(chunk.to_vec())),
+        );
+        self
+    }
+
     pub fn with_leaders(&mut self, leaders_ids: Vec<ConsensusLeaderId>) -> &mut Self {
         self.blockchain_configuration.consensus_leader_ids = leaders_ids;
         self
     }
+    /// selects BFT with the given leaders, which then produce the blocks in
+    /// turn, in the exact order they are supplied
+    pub fn with_bft_leaders_ordered(&mut self, leaders_ids: Vec<ConsensusLeaderId>) -> &mut Self {
+        self.with_block0_consensus(ConsensusVersion::Bft)
+            .with_leaders(leaders_ids)
+    }
     pub fn with_block0_consensus(&mut self, block0_consensus: ConsensusVersion) -> &mut Self {
         self.blockchain_configuration.block0_consensus = block0_consensus;
         self
@@ -125,6 +191,18 @@ impl Block0ConfigurationBuilder {
         self
     }
 
+    /// fill the reward pot with `total_reward_supply` and draw from it every
+    /// epoch according to `reward_parameters`
+    pub fn with_reward_params(
+        &mut self,
+        total_reward_supply: Value,
+        reward_parameters: RewardParams,
+    ) -> &mut Self {
+        self.blockchain_configuration.total_reward_supply = Some(total_reward_supply);
+        self.blockchain_configuration.reward_parameters = Some(reward_parameters);
+        self
+    }
+
     pub fn with_certs(&mut self, certs: Vec<Initial>) -> &mut Self {
         self.initial.extend(certs.iter().cloned());
         self
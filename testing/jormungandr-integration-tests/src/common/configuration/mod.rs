@@ -17,7 +17,7 @@ mod secret_model_factory;
 
 pub use block0_config_builder::Block0ConfigurationBuilder;
 pub use jormungandr_config::JormungandrParams;
-pub use node_config::TestConfig;
+pub use node_config::{assert_node_config_round_trips, TestConfig};
 pub use node_config_builder::NodeConfigBuilder;
 pub use secret_model_factory::{write_secret, SecretModelFactory};
 
@@ -38,3 +38,27 @@ impl TestConfig for NodeConfig {
         self.rest.listen = addr;
     }
 }
+
+/// Serializes the configuration to YAML, as written for the node to parse,
+/// and checks it deserializes back to the same configuration with the
+/// REST and p2p listen settings present.
+pub fn assert_node_config_round_trips(config: &NodeConfig) {
+    let yaml = serde_yaml::to_string(config).expect("cannot serialize node config");
+
+    let document: serde_yaml::Value =
+        serde_yaml::from_str(&yaml).expect("cannot parse serialized node config");
+    for (section, field) in &[("rest", "listen"), ("p2p", "public_address")] {
+        assert!(
+            !document[*section][*field].is_null(),
+            "node config is missing '{}.{}': {}",
+            section,
+            field,
+            yaml
+        );
+    }
+
+    let deserialized: NodeConfig =
+        serde_yaml::from_str(&yaml).expect("cannot deserialize node config");
+    let round_tripped = serde_yaml::to_string(&deserialized).expect("cannot serialize node config");
+    assert_eq!(yaml, round_tripped, "node config does not round trip");
+}
@@ -4,7 +4,8 @@ use std::path::PathBuf;
 
 use jormungandr_lib::{
     interfaces::{
-        Explorer, Log, Mempool, NodeConfig, P2p, Policy, Rest, TopicsOfInterest, TrustedPeer,
+        AccountHistory, Explorer, Log, Mempool, NodeConfig, P2p, Policy, Rest, TopicsOfInterest,
+        TrustedPeer,
     },
     time::Duration,
 };
@@ -16,6 +17,7 @@ pub struct NodeConfigBuilder {
     pub p2p: P2p,
     pub mempool: Option<Mempool>,
     pub explorer: Explorer,
+    pub account_history: Option<AccountHistory>,
 }
 
 const DEFAULT_HOST: &str = "127.0.0.1";
@@ -65,6 +67,7 @@ impl NodeConfigBuilder {
             },
             mempool: Some(Mempool::default()),
             explorer: Explorer { enabled: false },
+            account_history: None,
         }
     }
 
@@ -73,6 +76,14 @@ impl NodeConfigBuilder {
         self
     }
 
+    pub fn with_account_history(&mut self, max_entries: usize) -> &mut Self {
+        self.account_history = Some(AccountHistory {
+            enabled: true,
+            max_entries,
+        });
+        self
+    }
+
     pub fn with_policy(&mut self, policy: Policy) -> &mut Self {
         self.p2p.policy = Some(policy);
         self
@@ -116,6 +127,7 @@ impl NodeConfigBuilder {
             p2p: self.p2p.clone(),
             mempool: self.mempool.clone(),
             explorer: self.explorer.clone(),
+            account_history: self.account_history.clone(),
             bootstrap_from_trusted_peers: Some(!self.p2p.trusted_peers.is_empty()),
             skip_bootstrap: Some(self.p2p.trusted_peers.is_empty()),
         }
@@ -1,2 +1,16 @@
 pub mod commands;
 pub mod wrapper;
+
+use jormungandr_lib::interfaces::{Certificate, CertificateFromBech32Error, SignedCertificate};
+
+/// decode a certificate as printed by `jcli certificate new`
+pub fn parse_certificate_bech32(s: &str) -> Result<Certificate, CertificateFromBech32Error> {
+    Certificate::from_bech32(s.trim())
+}
+
+/// decode a signed certificate as written by `jcli certificate sign`
+pub fn parse_signed_certificate_bech32(
+    s: &str,
+) -> Result<SignedCertificate, CertificateFromBech32Error> {
+    SignedCertificate::from_bech32(s.trim())
+}
@@ -11,21 +11,47 @@ use assert_fs::{NamedTempFile, TempDir};
 use chain_impl_mockchain::block::BlockDate;
 use std::{
     path::{Path, PathBuf},
-    process::Command,
+    process::{Command, Output},
+    time::Duration,
 };
 
-#[derive(Debug, Default)]
+const DEFAULT_ATTEMPTS: u32 = 1;
+const DEFAULT_BACKOFF: Duration = Duration::from_millis(500);
+
+#[derive(Debug)]
 pub struct JCLICertificateWrapper {
     commands: CertificateCommands,
+    attempts: u32,
+    backoff: Duration,
+}
+
+impl Default for JCLICertificateWrapper {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl JCLICertificateWrapper {
     pub fn new() -> JCLICertificateWrapper {
         JCLICertificateWrapper {
             commands: CertificateCommands::new(),
+            attempts: DEFAULT_ATTEMPTS,
+            backoff: DEFAULT_BACKOFF,
         }
     }
 
+    /// make up to `attempts` attempts to run jcli when it cannot be spawned,
+    /// jcli exiting with an error is never retried
+    pub fn with_retries(mut self, attempts: u32, backoff: Duration) -> Self {
+        self.attempts = attempts;
+        self.backoff = backoff;
+        self
+    }
+
+    fn run(&self, command: Command) -> Output {
+        process_utils::run_process_with_retry_and_get_output(command, self.attempts, self.backoff)
+    }
+
     pub fn assert_new_vote_plan(
         &self,
         proposal_id: &str,
@@ -61,7 +87,7 @@ impl JCLICertificateWrapper {
     }
 
     fn assert_new_certificate(&self, command: Command) -> String {
-        let output = process_utils::run_process_and_get_output(command);
+        let output = self.run(command);
         let certification = output.as_single_line();
         process_assert::assert_process_exited_successfully(output);
         certification
@@ -98,7 +124,7 @@ impl JCLICertificateWrapper {
     pub fn assert_get_stake_pool_id(&self, input_file: &Path) -> String {
         println!("Running get stake pool id...");
         let temp_file = NamedTempFile::new("stake_pool.id").unwrap();
-        let output = process_utils::run_process_and_get_output(
+        let output = self.run(
             self.commands
                 .get_stake_pool_id_command(&input_file, temp_file.path()),
         );
@@ -109,7 +135,7 @@ impl JCLICertificateWrapper {
 
     pub fn assert_sign(&self, signing_key: &Path, input_file: &Path, output_file: &Path) -> String {
         println!("Running sign certification...");
-        let output = process_utils::run_process_and_get_output(self.commands.get_sign_command(
+        let output = self.run(self.commands.get_sign_command(
             &signing_key,
             &input_file,
             &output_file,
@@ -177,9 +203,7 @@ impl JCLICertificateWrapper {
 
     pub fn assert_new_stake_pool_retirement(&self, stake_pool_id: &str) -> String {
         println!("Running create retirement certification...");
-        let output = process_utils::run_process_and_get_output(
-            self.commands.get_retire_command(&stake_pool_id, 0u64),
-        );
+        let output = self.run(self.commands.get_retire_command(&stake_pool_id, 0u64));
         let certification = output.as_single_line();
         process_assert::assert_process_exited_successfully(output);
         certification
@@ -20,10 +20,19 @@ use assert_fs::fixture::ChildPath;
 use assert_fs::prelude::*;
 use assert_fs::TempDir;
 use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("cannot seal transaction: expected {expected} witness(es), one per input, but {actual} were added")]
+    WitnessCountMismatch { expected: usize, actual: usize },
+}
 
 pub struct JCLITransactionWrapper {
     staging_dir: TempDir,
     commands: TransactionCommands,
+    inputs_count: usize,
+    witnesses_count: usize,
     pub genesis_hash: Hash,
 }
 
@@ -33,6 +42,8 @@ impl JCLITransactionWrapper {
         JCLITransactionWrapper {
             staging_dir,
             commands: TransactionCommands::new(),
+            inputs_count: 0,
+            witnesses_count: 0,
             genesis_hash: Hash::from_hex(genesis_hash).unwrap(),
         }
     }
@@ -100,6 +111,8 @@ impl JCLITransactionWrapper {
 
     fn reset_staging_dir(&mut self) {
         self.staging_dir = TempDir::new().unwrap();
+        self.inputs_count = 0;
+        self.witnesses_count = 0;
     }
 
     pub fn assert_add_input(&mut self, tx_id: &Hash, tx_index: u8, amount: Value) -> &mut Self {
@@ -111,6 +124,7 @@ impl JCLITransactionWrapper {
                 self.staging_file().path(),
             ));
         process_assert::assert_process_exited_successfully(output);
+        self.inputs_count += 1;
         self
     }
 
@@ -165,6 +179,7 @@ impl JCLITransactionWrapper {
                 self.staging_file().path(),
             ));
         process_assert::assert_process_exited_successfully(output);
+        self.inputs_count += 1;
         self
     }
 
@@ -356,10 +371,32 @@ impl JCLITransactionWrapper {
                 .get_add_witness_command(&witness.file, self.staging_file().path()),
         );
         process_assert::assert_process_exited_successfully(output);
+        self.witnesses_count += 1;
         self
     }
 
+    /// checks that as many witnesses as inputs were added through this
+    /// wrapper, as sealing would otherwise produce a fragment rejected
+    /// by the ledger
+    pub fn check_witnesses_count(&self) -> Result<(), Error> {
+        if self.inputs_count != self.witnesses_count {
+            return Err(Error::WitnessCountMismatch {
+                expected: self.inputs_count,
+                actual: self.witnesses_count,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn seal(&mut self) -> Result<&mut Self, Error> {
+        self.check_witnesses_count()?;
+        Ok(self.assert_seal())
+    }
+
     pub fn assert_seal(&mut self) -> &mut Self {
+        if let Err(err) = self.check_witnesses_count() {
+            panic!("{}", err);
+        }
         let output = process_utils::run_process_and_get_output(
             self.commands.get_seal_command(self.staging_file().path()),
         );
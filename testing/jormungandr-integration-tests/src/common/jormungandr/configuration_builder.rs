@@ -7,22 +7,36 @@ use crate::common::{
     jormungandr::JormungandrProcess,
     startup::{build_genesis_block, create_new_key_pair},
 };
+use chain_addr::Discrimination;
 use chain_crypto::Ed25519;
 use chain_impl_mockchain::{chaintypes::ConsensusVersion, fee::LinearFee};
 use jormungandr_lib::interfaces::{
-    ActiveSlotCoefficient, CommitteeIdDef, ConsensusLeaderId, EpochStabilityDepth, Initial,
-    InitialUTxO, KESUpdateSpeed, Log, LogEntry, LogOutput, Mempool, NodeConfig, NodeSecret,
-    NumberOfSlotsPerEpoch, Policy, SignedCertificate, SlotDuration, TrustedPeer,
+    ActiveSlotCoefficient, Block0Configuration, CommitteeIdDef, ConsensusLeaderId,
+    EpochStabilityDepth, Initial, InitialUTxO, KESUpdateSpeed, Log, LogEntry, LogOutput, Mempool,
+    NodeConfig, NodeSecret, NumberOfSlotsPerEpoch, Policy, SignedCertificate, SlotDuration,
+    TrustedPeer,
 };
 
 use assert_fs::fixture::{ChildPath, PathChild};
 use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ConfigurationError {
+    #[error("block0 uses {expected:?} discrimination but initial fund address {address} uses {actual:?} discrimination")]
+    DiscriminationMismatch {
+        address: String,
+        expected: Discrimination,
+        actual: Discrimination,
+    },
+}
 
 pub struct ConfigurationBuilder {
     funds: Vec<Initial>,
     certs: Vec<Initial>,
     block0_hash: Option<String>,
     block0_consensus: ConsensusVersion,
+    discrimination: Discrimination,
     consensus_genesis_praos_active_slot_coeff: ActiveSlotCoefficient,
     slots_per_epoch: NumberOfSlotsPerEpoch,
     slot_duration: SlotDuration,
@@ -52,6 +66,7 @@ impl ConfigurationBuilder {
             secrets: vec![],
             block0_hash: None,
             block0_consensus: ConsensusVersion::Bft,
+            discrimination: Discrimination::Test,
             slots_per_epoch: NumberOfSlotsPerEpoch::new(100).unwrap(),
             slot_duration: SlotDuration::new(1).unwrap(),
             epoch_stability_depth: 2600u32.into(),
@@ -119,6 +134,11 @@ impl ConfigurationBuilder {
         self
     }
 
+    pub fn with_account_history(&mut self, max_entries: usize) -> &mut Self {
+        self.node_config_builder.with_account_history(max_entries);
+        self
+    }
+
     pub fn with_storage(&mut self, temp_dir: &ChildPath) -> &mut Self {
         self.node_config_builder
             .with_storage(temp_dir.path().into());
@@ -130,6 +150,11 @@ impl ConfigurationBuilder {
         self
     }
 
+    pub fn with_discrimination(&mut self, discrimination: Discrimination) -> &mut Self {
+        self.discrimination = discrimination;
+        self
+    }
+
     pub fn with_consensus_genesis_praos_active_slot_coeff(
         &mut self,
         active_slot_coeff: ActiveSlotCoefficient,
@@ -202,6 +227,16 @@ impl ConfigurationBuilder {
     }
 
     pub fn build(&self, temp_dir: &impl PathChild) -> JormungandrParams<NodeConfig> {
+        self.try_build(temp_dir).unwrap()
+    }
+
+    /// same as `build` but checks the assembled block0 before encoding it,
+    /// so an inconsistent configuration is reported before the node is
+    /// ever launched
+    pub fn try_build(
+        &self,
+        temp_dir: &impl PathChild,
+    ) -> Result<JormungandrParams<NodeConfig>, ConfigurationError> {
         let mut node_config = self.node_config_builder.build();
 
         let default_log_file = || temp_dir.child("node.log").path().to_path_buf();
@@ -232,6 +267,7 @@ impl ConfigurationBuilder {
             .with_initial(initial)
             .with_leaders(leaders_ids)
             .with_block0_consensus(self.block0_consensus.clone())
+            .with_discrimination(self.discrimination)
             .with_kes_update_speed(self.kes_update_speed.clone())
             .with_slots_per_epoch(self.slots_per_epoch)
             .with_slot_duration(self.slot_duration)
@@ -240,6 +276,7 @@ impl ConfigurationBuilder {
             .with_linear_fees(self.linear_fees.clone())
             .with_committee_ids(self.committee_ids.clone())
             .build();
+        check_discrimination(&block0_config)?;
 
         let path_to_output_block = build_genesis_block(&block0_config, temp_dir);
         let genesis_block_hash = match self.block0_hash {
@@ -282,6 +319,27 @@ impl ConfigurationBuilder {
 
         params.write_node_config();
 
-        params
+        Ok(params)
+    }
+}
+
+/// node secrets only hold keys, which are discrimination agnostic, so the
+/// addresses of the initial funds are the only place a mismatch can hide
+fn check_discrimination(block0_config: &Block0Configuration) -> Result<(), ConfigurationError> {
+    let expected = block0_config.blockchain_configuration.discrimination;
+    for initial in &block0_config.initial {
+        if let Initial::Fund(utxos) = initial {
+            for utxo in utxos {
+                let actual = utxo.address.1.discrimination();
+                if actual != expected {
+                    return Err(ConfigurationError::DiscriminationMismatch {
+                        address: utxo.address.to_string(),
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
     }
+    Ok(())
 }
@@ -8,7 +8,7 @@ pub use benchmark::storage_loading_benchmark_from_log;
 use chain_core::property::Fragment as _;
 use chain_impl_mockchain::fragment::Fragment;
 use chain_impl_mockchain::fragment::FragmentId;
-pub use configuration_builder::ConfigurationBuilder;
+pub use configuration_builder::{ConfigurationBuilder, ConfigurationError};
 use jormungandr_lib::crypto::hash::Hash;
 use jormungandr_lib::interfaces::BlockDate;
 use jormungandr_lib::interfaces::FragmentLog;
@@ -3,8 +3,9 @@ use chain_impl_mockchain::fragment::{Fragment, FragmentId};
 use jormungandr_lib::{
     crypto::hash::Hash,
     interfaces::{
-        EnclaveLeaderId, EpochRewardsInfo, FragmentLog, NodeStatsDto, PeerRecord, PeerStats,
-        StakeDistributionDto,
+        AccountHistoryEntry, BlockDate, BlockchainConfiguration, EnclaveLeaderId, EpochRewardsInfo,
+        FragmentLog, FragmentStatusDto, NodeStatsDto, PeerRecord, PeerStats, StakeDistributionDto,
+        VotePlanWithId,
     },
 };
 use jormungandr_testing_utils::testing::MemPoolCheck;
@@ -67,6 +68,14 @@ impl JormungandrRest {
             .map_err(RestError::CannotDeserialize)
     }
 
+    pub fn block0_settings(&self) -> Result<BlockchainConfiguration, RestError> {
+        serde_json::from_str(&self.inner.block0_settings()?).map_err(RestError::CannotDeserialize)
+    }
+
+    pub fn vote_plans(&self) -> Result<Vec<VotePlanWithId>, RestError> {
+        serde_json::from_str(&self.inner.vote_plans()?).map_err(RestError::CannotDeserialize)
+    }
+
     pub fn stats(&self) -> Result<NodeStatsDto, RestError> {
         let stats = &self.inner.stats()?;
         serde_json::from_str(stats).map_err(RestError::CannotDeserialize)
@@ -100,6 +109,14 @@ impl JormungandrRest {
         self.inner.fragment_logs()
     }
 
+    pub fn fragment_status(
+        &self,
+        fragment_id: &FragmentId,
+    ) -> Result<FragmentStatusDto, RestError> {
+        serde_json::from_str(&self.inner.fragment_status(fragment_id)?)
+            .map_err(RestError::CannotDeserialize)
+    }
+
     pub fn leaders(&self) -> Result<Vec<EnclaveLeaderId>, RestError> {
         let leaders = self.inner.leaders()?;
         let leaders: Vec<EnclaveLeaderId> = if leaders.is_empty() {
@@ -110,6 +127,16 @@ impl JormungandrRest {
         Ok(leaders)
     }
 
+    pub fn leaders_schedule(&self, pool_id: &str) -> Result<Vec<BlockDate>, RestError> {
+        serde_json::from_str(&self.inner.leaders_schedule(pool_id)?)
+            .map_err(RestError::CannotDeserialize)
+    }
+
+    pub fn account_history(&self, account_id: &str) -> Result<Vec<AccountHistoryEntry>, RestError> {
+        serde_json::from_str(&self.inner.account_history(account_id)?)
+            .map_err(RestError::CannotDeserialize)
+    }
+
     pub fn send_fragment(&self, fragment: Fragment) -> Result<MemPoolCheck, reqwest::Error> {
         self.inner.send_fragment(fragment)
     }
@@ -63,6 +63,18 @@ impl BackwardCompatibleRest {
         Ok(response_text)
     }
 
+    pub fn block0_settings(&self) -> Result<String, reqwest::Error> {
+        let response_text = self.get("settings/block0")?.text()?;
+        self.print_response_text(&response_text);
+        Ok(response_text)
+    }
+
+    pub fn vote_plans(&self) -> Result<String, reqwest::Error> {
+        let response_text = self.get("vote/active/plans")?.text()?;
+        self.print_response_text(&response_text);
+        Ok(response_text)
+    }
+
     pub fn stats(&self) -> Result<String, reqwest::Error> {
         self.get("node/stats")?.text()
     }
@@ -108,10 +120,31 @@ impl BackwardCompatibleRest {
         Ok(logs)
     }
 
+    pub fn fragment_status(&self, fragment_id: &FragmentId) -> Result<String, reqwest::Error> {
+        let request = format!("fragment/{}/status", fragment_id);
+        let response_text = self.get(&request)?.text()?;
+        self.print_response_text(&response_text);
+        Ok(response_text)
+    }
+
     pub fn leaders(&self) -> Result<String, reqwest::Error> {
         self.get("leaders")?.text()
     }
 
+    pub fn leaders_schedule(&self, pool_id: &str) -> Result<String, reqwest::Error> {
+        let request = format!("leaders/schedule?pool_id={}", pool_id);
+        let response_text = self.get(&request)?.error_for_status()?.text()?;
+        self.print_response_text(&response_text);
+        Ok(response_text)
+    }
+
+    pub fn account_history(&self, account_id: &str) -> Result<String, reqwest::Error> {
+        let request = format!("account/{}/history", account_id);
+        let response_text = self.get(&request)?.text()?;
+        self.print_response_text(&response_text);
+        Ok(response_text)
+    }
+
     fn post(
         &self,
         path: &str,
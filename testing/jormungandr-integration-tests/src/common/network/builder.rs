@@ -6,8 +6,8 @@ use jormungandr_lib::interfaces::{
     ActiveSlotCoefficient, KESUpdateSpeed, NodeSecret, NumberOfSlotsPerEpoch, SlotDuration,
 };
 use jormungandr_testing_utils::testing::network_builder::{
-    Blockchain, Node, NodeAlias, NodeSetting, Random, Seed, Settings, SpawnParams, TopologyBuilder,
-    WalletAlias, WalletTemplate,
+    Blockchain, LeadershipMode, Node, NodeAlias, NodeSetting, Random, Seed, Settings, SpawnParams,
+    TopologyBuilder, WalletAlias, WalletTemplate, WalletType,
 };
 
 use assert_fs::TempDir;
@@ -18,6 +18,8 @@ pub struct NetworkBuilder {
     blockchain: Option<Blockchain>,
     wallets: Vec<WalletTemplate>,
     configs: Vec<SpawnParams>,
+    stake_pools: HashMap<NodeAlias, usize>,
+    seed: Option<Seed>,
 }
 
 impl NetworkBuilder {
@@ -37,11 +39,42 @@ impl NetworkBuilder {
         self
     }
 
+    pub fn passive_relay(&mut self, alias: &str, trusted_peers: Vec<&str>) -> &mut Self {
+        let trusted_peers = trusted_peers.into_iter().map(str::to_string).collect();
+        self.topology_builder
+            .register_node(Node::passive(alias.to_string(), trusted_peers));
+        self
+    }
+
     pub fn custom_config(&mut self, spawn_params: Vec<&mut SpawnParams>) -> &mut Self {
         self.configs = spawn_params.iter().map(|x| (**x).clone()).collect();
         self
     }
 
+    pub fn stake_pools(&mut self, alias: &str, count: usize) -> &mut Self {
+        self.stake_pools.insert(alias.to_string(), count);
+        self
+    }
+
+    pub fn consensus(&mut self, consensus: ConsensusVersion) -> &mut Self {
+        let blockchain = self.blockchain.as_ref().unwrap();
+        self.blockchain = Some(Blockchain::new(
+            consensus,
+            *blockchain.slots_per_epoch(),
+            *blockchain.slot_duration(),
+            *blockchain.kes_update_speed(),
+            *blockchain.consensus_genesis_praos_active_slot_coeff(),
+        ));
+        self
+    }
+
+    /// seed the random generator used to create the wallets and node
+    /// secrets, so the same seed always produces the same block0 funds
+    pub fn seed(&mut self, seed: Seed) -> &mut Self {
+        self.seed = Some(seed);
+        self
+    }
+
     pub fn initials(&mut self, wallets: Vec<&mut WalletTemplateBuilder>) -> &mut Self {
         self.wallets.extend(wallets.iter().map(|x| x.build()));
         self
@@ -53,7 +86,10 @@ impl NetworkBuilder {
         let mut blockchain = self.blockchain.clone().unwrap();
         let nodes: HashMap<NodeAlias, NodeSetting> = topology
             .into_iter()
-            .map(|(alias, template)| {
+            .map(|(alias, mut template)| {
+                if let Some(count) = self.stake_pools.get(&alias) {
+                    template.set_stake_pools(*count);
+                }
                 let config = NodeConfigBuilder::new().build();
                 (
                     alias.clone(),
@@ -64,24 +100,29 @@ impl NetworkBuilder {
                             bft: None,
                             genesis: None,
                         },
+                        additional_secrets: Vec::new(),
                         node_topology: template,
                     },
                 )
             })
             .collect();
-        let seed = Seed::generate(rand::rngs::OsRng);
+        let seed = self
+            .seed
+            .unwrap_or_else(|| Seed::generate(rand::rngs::OsRng));
         let mut random = Random::new(seed);
 
-        for alias in nodes.keys() {
-            let leader: NodeAlias = alias.into();
-            blockchain.add_leader(leader);
+        for (alias, node) in &nodes {
+            if node.node_topology.leadership_mode() == LeadershipMode::Leader {
+                let leader: NodeAlias = alias.into();
+                blockchain.add_leader(leader);
+            }
         }
 
         for wallet in &self.wallets {
             blockchain.add_wallet(wallet.clone());
         }
 
-        let settings = Settings::new(nodes, blockchain, &mut random);
+        let settings = Settings::new(nodes, blockchain, &mut random)?;
         Controller::new(settings, temp_dir)
     }
 }
@@ -99,6 +140,8 @@ pub fn builder() -> NetworkBuilder {
         topology_builder: TopologyBuilder::new(),
         wallets: Vec::new(),
         configs: Vec::new(),
+        stake_pools: HashMap::new(),
+        seed: None,
     }
 }
 
@@ -107,22 +150,53 @@ pub struct WalletTemplateBuilder {
     value: Value,
     wallet_template: Option<WalletTemplate>,
     node_alias: Option<NodeAlias>,
+    delegate_ratios: Vec<(NodeAlias, u8)>,
+    wallet_type: WalletType,
 }
 
 impl WalletTemplateBuilder {
+    /// `count` account wallets aliased `{prefix}0` up to `{prefix}{count - 1}`,
+    /// each funded with `value`
+    pub fn accounts(prefix: &str, count: usize, value: u64) -> Vec<WalletTemplateBuilder> {
+        (0..count)
+            .map(|i| {
+                let mut wallet = wallet(&format!("{}{}", prefix, i));
+                wallet.with(value);
+                wallet
+            })
+            .collect()
+    }
+
     pub fn with(&mut self, value: u64) -> &mut Self {
         self.value = value.into();
         self
     }
 
+    pub fn utxo(&mut self) -> &mut Self {
+        self.wallet_type = WalletType::UTxO;
+        self
+    }
+
     pub fn delegated_to(&mut self, delegated_to: &str) -> &mut Self {
         self.node_alias = Some(delegated_to.to_string());
         self
     }
 
+    pub fn delegated_to_many(&mut self, ratios: Vec<(&str, u8)>) -> &mut Self {
+        self.delegate_ratios = ratios
+            .into_iter()
+            .map(|(alias, weight)| (alias.to_string(), weight))
+            .collect();
+        self
+    }
+
     pub fn build(&self) -> WalletTemplate {
-        let mut wallet = WalletTemplate::new_account(self.alias.clone(), self.value);
+        let mut wallet = match self.wallet_type {
+            WalletType::Account => WalletTemplate::new_account(self.alias.clone(), self.value),
+            WalletType::UTxO => WalletTemplate::new_utxo(self.alias.clone(), self.value),
+        };
         *wallet.delegate_mut() = self.node_alias.clone();
+        *wallet.delegate_ratios_mut() = self.delegate_ratios.clone();
         wallet
     }
 }
@@ -133,6 +207,8 @@ pub fn wallet(alias: &str) -> WalletTemplateBuilder {
         value: 0u64.into(),
         wallet_template: None,
         node_alias: None,
+        delegate_ratios: Vec::new(),
+        wallet_type: WalletType::Account,
     }
 }
 
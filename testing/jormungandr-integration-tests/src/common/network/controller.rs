@@ -3,11 +3,11 @@ use crate::common::{
     jormungandr::starter::{Starter, StartupError},
     jormungandr::JormungandrProcess,
 };
-use chain_impl_mockchain::header::HeaderId;
-use jormungandr_lib::interfaces::{Log, LogEntry, LogOutput, NodeConfig};
+use chain_impl_mockchain::{header::HeaderId, transaction::UtxoPointer};
+use jormungandr_lib::interfaces::{Block0Configuration, Log, LogEntry, LogOutput, NodeConfig};
 use jormungandr_testing_utils::testing::network_builder::NodeSetting;
 use jormungandr_testing_utils::testing::network_builder::{
-    LeadershipMode, PersistenceMode, Settings, SpawnParams, Wallet,
+    LeadershipMode, PersistenceMode, Settings, SettingsError, SpawnParams, Wallet,
 };
 
 use assert_fs::fixture::FixtureError;
@@ -22,6 +22,8 @@ pub enum ControllerError {
     NodeNotFound(String),
     #[error("wallet not found {0}")]
     WalletNotFound(String),
+    #[error("no block0 utxo for wallet {0}")]
+    WalletUtxoNotFound(String),
     #[error("io error")]
     IOError(#[from] std::io::Error),
     #[error("fixture filesystem error")]
@@ -30,6 +32,8 @@ pub enum ControllerError {
     SerializationError(#[from] serde_yaml::Error),
     #[error("node startup error")]
     SpawnError(#[from] StartupError),
+    #[error(transparent)]
+    Settings(#[from] SettingsError),
 }
 
 pub struct Controller {
@@ -58,6 +62,10 @@ impl Controller {
         })
     }
 
+    pub fn block0_configuration(&self) -> &Block0Configuration {
+        &self.settings.block0
+    }
+
     pub fn wallet(&mut self, wallet: &str) -> Result<Wallet, ControllerError> {
         if let Some(wallet) = self.settings.wallets.remove(wallet) {
             Ok(wallet)
@@ -66,11 +74,17 @@ impl Controller {
         }
     }
 
+    pub fn wallet_utxo(&self, wallet: &str) -> Result<UtxoPointer, ControllerError> {
+        self.settings
+            .wallet_utxo(wallet)
+            .ok_or_else(|| ControllerError::WalletUtxoNotFound(wallet.to_owned()))
+    }
+
     pub fn node_config(&self, alias: &str) -> Result<NodeConfig, ControllerError> {
         Ok(self.node_settings(alias)?.config.clone())
     }
 
-    fn node_settings(&self, alias: &str) -> Result<&NodeSetting, ControllerError> {
+    pub fn node_settings(&self, alias: &str) -> Result<&NodeSetting, ControllerError> {
         if let Some(node_setting) = self.settings.nodes.get(alias) {
             Ok(node_setting)
         } else {
@@ -141,16 +155,26 @@ impl Controller {
         let yaml = serde_yaml::to_string(&config)?;
         config_file.write_str(&yaml)?;
 
-        let secret_file = dir.child("node_secret.yaml");
-        let yaml = serde_yaml::to_string(node_setting.secrets())?;
-        secret_file.write_str(&yaml)?;
+        let secrets =
+            std::iter::once(node_setting.secrets()).chain(node_setting.additional_secrets().iter());
+        let mut secret_files = Vec::new();
+        for (i, secret) in secrets.enumerate() {
+            let secret_file = if i == 0 {
+                dir.child("node_secret.yaml")
+            } else {
+                dir.child(&format!("node_secret-{}.yaml", i))
+            };
+            let yaml = serde_yaml::to_string(secret)?;
+            secret_file.write_str(&yaml)?;
+            secret_files.push(secret_file.path().to_path_buf());
+        }
 
         let params = JormungandrParams::new(
             config,
             config_file.path(),
             &self.block0_file,
             self.block0_hash.to_string(),
-            &[secret_file.path()],
+            secret_files,
             self.settings.block0.clone(),
             false,
             log_file_path,
@@ -1,5 +1,5 @@
 mod builder;
 mod controller;
 
-pub use builder::{builder, params, wallet};
+pub use builder::{builder, params, wallet, WalletTemplateBuilder};
 pub use controller::{Controller, ControllerError};
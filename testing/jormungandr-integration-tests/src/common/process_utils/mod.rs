@@ -9,8 +9,10 @@ pub use wait::{Wait, WaitBuilder};
 
 use self::output_extensions::ProcessOutput;
 use std::{
+    io,
     process::{Command, Output, Stdio},
-    thread, time,
+    thread,
+    time::{self, Duration},
 };
 use thiserror::Error;
 
@@ -27,15 +29,61 @@ pub enum ProcessError {
 /// * `command` - Command which will be invoked
 ///
 pub fn run_process_and_get_output(mut command: Command) -> Output {
+    try_run_process_and_get_output(&mut command).expect("failed to execute process")
+}
+
+/// Runs command like `run_process_and_get_output`, making up to `attempts`
+/// attempts when the process cannot be spawned or waited for. The delay
+/// between attempts starts at `backoff` and doubles after each failure.
+/// A process that ran is never retried, whatever its exit code.
+///
+/// # Panics
+///
+/// Panics if none of the attempts could run the process.
+///
+pub fn run_process_with_retry_and_get_output(
+    mut command: Command,
+    attempts: u32,
+    backoff: Duration,
+) -> Output {
+    retry_on_io_error(attempts, backoff, || {
+        try_run_process_and_get_output(&mut command)
+    })
+    .expect("failed to execute process")
+}
+
+/// Calls `run` up to `attempts` times (at least once) until it does not
+/// return an IO error, sleeping `backoff` (doubled each time) in between
+pub fn retry_on_io_error<F>(attempts: u32, backoff: Duration, mut run: F) -> io::Result<Output>
+where
+    F: FnMut() -> io::Result<Output>,
+{
+    let mut backoff = backoff;
+    let mut attempt = 1;
+    loop {
+        match run() {
+            Err(err) if attempt < attempts => {
+                println!(
+                    "Attempt {}/{} failed to run process: {}, retrying in {:?}",
+                    attempt, attempts, err, backoff
+                );
+                thread::sleep(backoff);
+                backoff *= 2;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+fn try_run_process_and_get_output(command: &mut Command) -> io::Result<Output> {
     // FIXME: switch to something like assert_cmd to wait with a timeout
     println!("Running command: {:?}", &command);
     let content = command
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
-        .spawn()
-        .unwrap()
-        .wait_with_output()
-        .expect("failed to execute process");
+        .spawn()?
+        .wait_with_output()?;
 
     if content.as_lossy_string() != "" {
         println!("Output: {}", content.as_lossy_string());
@@ -44,7 +92,7 @@ pub fn run_process_and_get_output(mut command: Command) -> Output {
         println!("Error: {}", content.err_as_lossy_string());
     }
     println!();
-    content
+    Ok(content)
 }
 
 /// Runs command for n times with m second interval.
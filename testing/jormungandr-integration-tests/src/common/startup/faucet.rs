@@ -0,0 +1,82 @@
+use crate::common::{
+    jcli_wrapper,
+    jormungandr::{JormungandrRest, RestError},
+    process_utils,
+};
+use chain_impl_mockchain::fragment::FragmentId;
+use jormungandr_lib::{
+    crypto::hash::Hash,
+    interfaces::{Address, FragmentStatus, Value},
+};
+use jormungandr_testing_utils::wallet::{Wallet, WalletError};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+const FUND_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Error)]
+pub enum FaucetError {
+    #[error("cannot build funding transaction")]
+    Wallet(#[from] WalletError),
+    #[error("cannot send funding transaction")]
+    Send(#[from] reqwest::Error),
+    #[error("cannot get fragment logs")]
+    Rest(#[from] RestError),
+    #[error("block0 hash returned by node is malformed: {0}")]
+    MalformedBlock0Hash(String),
+    #[error("funding transaction {fragment_id} rejected: {reason}")]
+    Rejected {
+        fragment_id: FragmentId,
+        reason: String,
+    },
+    #[error("funding transaction {fragment_id} not in block after {timeout:?}")]
+    Timeout {
+        fragment_id: FragmentId,
+        timeout: Duration,
+    },
+}
+
+/// Sends `value` from `faucet` to `address` and waits until the
+/// transaction is in a block. Returns the id of the funding transaction.
+pub fn fund(
+    rest_url: &str,
+    faucet: &mut Wallet,
+    address: Address,
+    value: Value,
+) -> Result<FragmentId, FaucetError> {
+    let settings = jcli_wrapper::assert_get_rest_settings(rest_url);
+    let block0_hash: Hash = settings
+        .block0_hash
+        .parse()
+        .map_err(|_| FaucetError::MalformedBlock0Hash(settings.block0_hash.clone()))?;
+
+    let transaction = faucet.transaction_to(&block0_hash, &settings.fees, address, value)?;
+    let rest = JormungandrRest::new(rest_url.to_string());
+    let fragment_id = rest.send_fragment(transaction)?.fragment_id().clone();
+
+    let start = Instant::now();
+    loop {
+        if let Some(log) = rest.fragment_logs()?.get(&fragment_id) {
+            match log.status() {
+                FragmentStatus::InABlock { .. } => {
+                    faucet.confirm_transaction();
+                    return Ok(fragment_id);
+                }
+                FragmentStatus::Rejected { reason } => {
+                    return Err(FaucetError::Rejected {
+                        fragment_id,
+                        reason: reason.clone(),
+                    })
+                }
+                FragmentStatus::Pending => (),
+            }
+        }
+        if start.elapsed() > FUND_TIMEOUT {
+            return Err(FaucetError::Timeout {
+                fragment_id,
+                timeout: FUND_TIMEOUT,
+            });
+        }
+        process_utils::sleep(1);
+    }
+}
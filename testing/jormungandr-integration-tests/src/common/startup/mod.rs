@@ -1,26 +1,37 @@
+pub mod faucet;
+
 use crate::common::{
     configuration::SecretModelFactory,
     jcli_wrapper,
-    jormungandr::{ConfigurationBuilder, JormungandrProcess, Starter, StartupError},
+    jormungandr::{
+        ConfigurationBuilder, JormungandrProcess, JormungandrRest, RestError, Starter, StartupError,
+    },
     process_utils,
 };
+use chain_core::mempack;
 use chain_crypto::{AsymmetricKey, Ed25519};
-use chain_impl_mockchain::chaintypes::ConsensusVersion;
+use chain_impl_mockchain::{
+    chaintypes::ConsensusVersion,
+    fragment::{Fragment, FragmentId},
+};
 use jormungandr_lib::{
     crypto::key::{Identifier, KeyPair},
     interfaces::{
-        Block0Configuration, ConsensusLeaderId, InitialUTxO, NodeSecret, SignedCertificate,
+        Block0Configuration, ConsensusLeaderId, FragmentStatus, InitialUTxO, NodeSecret,
+        SignedCertificate, StakeDistributionDto, VotePlanWithId,
     },
 };
 use jormungandr_testing_utils::{
     stake_pool::StakePool,
-    testing::{signed_delegation_cert, signed_stake_pool_cert},
+    testing::{signed_delegation_cert, signed_stake_pool_cert, Block0ConfigurationExtension},
     wallet::Wallet,
 };
 
 use assert_fs::fixture::{ChildPath, PathChild, TempDir};
 use assert_fs::prelude::*;
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use thiserror::Error;
 
 pub fn build_genesis_block(
     block0_config: &Block0Configuration,
@@ -145,3 +156,218 @@ pub fn sleep_till_epoch(epoch_interval: u32, grace_period: u32, config: &Block0C
 pub fn sleep_till_next_epoch(grace_period: u32, config: &Block0Configuration) {
     sleep_till_epoch(1, grace_period, config);
 }
+
+#[derive(Debug, Error)]
+pub enum WaitForBlockHeightError {
+    #[error("cannot get node stats")]
+    Rest(#[from] RestError),
+    #[error("node stats do not contain last block height")]
+    MissingBlockHeight,
+    #[error("cannot parse last block height: {0}")]
+    MalformedBlockHeight(String),
+    #[error("block height {target} not reached after {timeout:?}, last height: {height}")]
+    Timeout {
+        target: u32,
+        height: u32,
+        timeout: Duration,
+    },
+}
+
+/// Polls node stats until the chain reaches `target` height.
+/// Returns the last observed height.
+pub fn wait_for_block_height(
+    rest_url: &str,
+    target: u32,
+    timeout: Duration,
+) -> Result<u32, WaitForBlockHeightError> {
+    let rest = JormungandrRest::new(rest_url.to_string());
+    let start = Instant::now();
+    loop {
+        let last_block_height = rest
+            .stats()?
+            .stats
+            .and_then(|stats| stats.last_block_height)
+            .ok_or(WaitForBlockHeightError::MissingBlockHeight)?;
+        let height: u32 = last_block_height
+            .parse()
+            .map_err(|_| WaitForBlockHeightError::MalformedBlockHeight(last_block_height))?;
+
+        if height >= target {
+            return Ok(height);
+        }
+        if start.elapsed() > timeout {
+            return Err(WaitForBlockHeightError::Timeout {
+                target,
+                height,
+                timeout,
+            });
+        }
+        process_utils::sleep(1);
+    }
+}
+
+const FRAGMENT_REJECTION_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Asserts the node behind `rest_url` runs on the block0 built from `config`
+/// by comparing the block0 hash it reports with the one computed locally
+pub fn assert_block0_hash_matches(rest_url: &str, config: &Block0Configuration) {
+    let expected = config.genesis_hash().to_string();
+    let actual = jcli_wrapper::assert_get_rest_settings(rest_url).block0_hash;
+    assert_eq!(
+        actual, expected,
+        "node reports block0 hash '{}' while the local block0 configuration hashes to '{}'",
+        actual, expected
+    );
+}
+
+/// Submits raw fragment through REST and asserts the node rejects it
+/// with a reason containing `expected_reason`
+pub fn assert_fragment_rejected(rest_url: &str, fragment_bytes: &[u8], expected_reason: &str) {
+    let fragment =
+        mempack::read_from_raw::<Fragment>(fragment_bytes).expect("cannot decode fragment");
+    let rest = JormungandrRest::new(rest_url.to_string());
+    let fragment_id = rest
+        .send_fragment(fragment)
+        .expect("cannot send fragment")
+        .fragment_id()
+        .clone();
+
+    let start = Instant::now();
+    loop {
+        let logs = rest.fragment_logs().expect("cannot get fragment logs");
+        match logs.get(&fragment_id).map(|log| log.status()) {
+            Some(FragmentStatus::Rejected { reason }) => {
+                assert!(
+                    reason.contains(expected_reason),
+                    "fragment '{}' rejected with '{}', expected reason containing '{}'",
+                    fragment_id,
+                    reason,
+                    expected_reason
+                );
+                return;
+            }
+            Some(FragmentStatus::InABlock { .. }) => {
+                panic!(
+                    "fragment '{}' should be rejected, but is in block",
+                    fragment_id
+                )
+            }
+            _ => (),
+        }
+        assert!(
+            start.elapsed() < FRAGMENT_REJECTION_TIMEOUT,
+            "fragment '{}' not rejected after {:?}",
+            fragment_id,
+            FRAGMENT_REJECTION_TIMEOUT
+        );
+        process_utils::sleep(1);
+    }
+}
+
+/// Ledger state of a node as exposed by its REST api, handed to the `confirm`
+/// closure of `submit_certificate_and_confirm`
+#[derive(Debug, Clone)]
+pub struct LedgerView {
+    pub stake_pools: Vec<String>,
+    pub stake_distribution: StakeDistributionDto,
+    pub vote_plans: Vec<VotePlanWithId>,
+}
+
+impl LedgerView {
+    pub fn fetch(rest: &JormungandrRest) -> Result<Self, RestError> {
+        Ok(LedgerView {
+            stake_pools: rest.stake_pools()?,
+            stake_distribution: rest.stake_distribution()?,
+            vote_plans: rest.vote_plans()?,
+        })
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SubmitCertificateError {
+    #[error("cannot send certificate fragment")]
+    Send(#[from] reqwest::Error),
+    #[error("cannot query the node")]
+    Rest(#[from] RestError),
+    #[error("certificate fragment '{fragment_id}' rejected: {reason}")]
+    Rejected {
+        fragment_id: FragmentId,
+        reason: String,
+    },
+    #[error("certificate fragment '{fragment_id}' effect not confirmed after {timeout:?}")]
+    Timeout {
+        fragment_id: FragmentId,
+        timeout: Duration,
+    },
+}
+
+/// Sends the signed certificate fragment to the node and polls its REST api
+/// until `confirm` accepts the resulting ledger view. Returns the view which
+/// got confirmed.
+pub fn submit_certificate_and_confirm(
+    rest_url: &str,
+    fragment: Fragment,
+    confirm: impl Fn(&LedgerView) -> bool,
+    timeout: Duration,
+) -> Result<LedgerView, SubmitCertificateError> {
+    let rest = JormungandrRest::new(rest_url.to_string());
+    let fragment_id = rest.send_fragment(fragment)?.fragment_id().clone();
+
+    let start = Instant::now();
+    loop {
+        if let Some(FragmentStatus::Rejected { reason }) = rest
+            .fragment_logs()?
+            .get(&fragment_id)
+            .map(|log| log.status().clone())
+        {
+            return Err(SubmitCertificateError::Rejected {
+                fragment_id,
+                reason,
+            });
+        }
+
+        let view = LedgerView::fetch(&rest)?;
+        if confirm(&view) {
+            return Ok(view);
+        }
+        if start.elapsed() > timeout {
+            return Err(SubmitCertificateError::Timeout {
+                fragment_id,
+                timeout,
+            });
+        }
+        process_utils::sleep(1);
+    }
+}
+
+/// Polls the stats of every node until they all report the same tip hash at
+/// the same height, panics with the last tip of every node on timeout
+pub fn assert_nodes_converged(rest_urls: &[&str], timeout: Duration) {
+    assert!(!rest_urls.is_empty(), "no node to check the convergence of");
+    let rests: Vec<JormungandrRest> = rest_urls
+        .iter()
+        .map(|rest_url| JormungandrRest::new(rest_url.to_string()))
+        .collect();
+
+    let start = Instant::now();
+    loop {
+        let tips: Vec<Option<(String, String)>> = rests
+            .iter()
+            .map(|rest| {
+                let stats = rest.stats().expect("cannot get node stats").stats?;
+                Some((stats.last_block_height?, stats.last_block_hash?))
+            })
+            .collect();
+
+        if tips[0].is_some() && tips.windows(2).all(|pair| pair[0] == pair[1]) {
+            return;
+        }
+        assert!(
+            start.elapsed() < timeout,
+            "nodes did not converge after {:?}, tips (height, hash): {:?}",
+            timeout,
+            rest_urls.iter().zip(tips.iter()).collect::<Vec<_>>()
+        );
+        process_utils::sleep(1);
+    }
+}
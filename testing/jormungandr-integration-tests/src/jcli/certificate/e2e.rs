@@ -1,12 +1,22 @@
 use crate::common::{
-    jcli_wrapper::certificate::wrapper::JCLICertificateWrapper, startup::create_new_key_pair,
+    configuration::Block0ConfigurationBuilder,
+    file_utils,
+    jcli_wrapper::certificate::{
+        parse_certificate_bech32, parse_signed_certificate_bech32, wrapper::JCLICertificateWrapper,
+    },
+    startup::create_new_key_pair,
 };
 
+use chain_addr::{Address, Discrimination, Kind};
 use chain_crypto::{Curve25519_2HashDH, Ed25519, SumEd25519_12};
+use chain_impl_mockchain::account::DelegationType;
+use jormungandr_lib::interfaces::{Certificate, Initial, InitialUTxO};
+use jormungandr_testing_utils::testing::Block0ConfigurationExtension;
 
 use assert_fs::prelude::*;
 use assert_fs::TempDir;
 use chain_impl_mockchain::block::BlockDate;
+use std::str::FromStr;
 
 #[test]
 pub fn test_create_and_sign_new_stake_delegation() {
@@ -78,3 +88,107 @@ pub fn test_create_vote_plan_certificate() {
 
     assert_ne!(certificate, "", "vote plan cert is empty");
 }
+
+#[test]
+pub fn test_stake_pool_registration_info_matches_jcli_input() {
+    let owner = create_new_key_pair::<Ed25519>();
+    let kes = create_new_key_pair::<SumEd25519_12>();
+    let vrf = create_new_key_pair::<Curve25519_2HashDH>();
+
+    let certificate_wrapper = JCLICertificateWrapper::new();
+    let certificate = certificate_wrapper.assert_new_stake_pool_registration(
+        &kes.identifier().to_bech32_str(),
+        &vrf.identifier().to_bech32_str(),
+        0,
+        1,
+        &owner.identifier().to_bech32_str(),
+        None,
+    );
+
+    let info = Certificate::from_str(certificate.trim())
+        .expect("cannot decode stake pool registration")
+        .pool_registration_info()
+        .expect("not a stake pool registration");
+
+    assert_eq!(info.owners, vec![owner.identifier()], "owners");
+    assert!(info.operators.is_empty(), "operators");
+    assert_eq!(info.kes_public_key, kes.identifier(), "kes public key");
+    assert_eq!(info.vrf_public_key, vrf.identifier(), "vrf public key");
+    assert_eq!(info.management_threshold, 1, "management threshold");
+}
+
+#[test]
+pub fn test_jcli_stake_delegation_applies_to_ledger() {
+    let temp_dir = TempDir::new().unwrap();
+    let owner = create_new_key_pair::<Ed25519>();
+    let kes = create_new_key_pair::<SumEd25519_12>();
+    let vrf = create_new_key_pair::<Curve25519_2HashDH>();
+    let owner_private_key_file = temp_dir.child("owner.private");
+    owner_private_key_file
+        .write_str(&owner.signing_key().to_bech32_str())
+        .unwrap();
+
+    let certificate_wrapper = JCLICertificateWrapper::new();
+    let registration = certificate_wrapper.assert_new_stake_pool_registration(
+        &kes.identifier().to_bech32_str(),
+        &vrf.identifier().to_bech32_str(),
+        0,
+        1,
+        &owner.identifier().to_bech32_str(),
+        None,
+    );
+    assert!(parse_certificate_bech32(&registration)
+        .unwrap()
+        .pool_registration_info()
+        .is_some());
+
+    let registration_file = temp_dir.child("stake_pool.cert");
+    registration_file.write_str(&registration).unwrap();
+    let stake_pool_id = certificate_wrapper.assert_get_stake_pool_id(registration_file.path());
+    let signed_registration_file = temp_dir.child("stake_pool.signcert");
+    certificate_wrapper.assert_sign(
+        owner_private_key_file.path(),
+        registration_file.path(),
+        signed_registration_file.path(),
+    );
+    let signed_registration =
+        parse_signed_certificate_bech32(&file_utils::read_file(signed_registration_file.path()))
+            .expect("cannot decode signed stake pool registration");
+    let signed_delegation = parse_signed_certificate_bech32(
+        &certificate_wrapper.assert_new_signed_stake_pool_delegation(
+            &stake_pool_id,
+            &owner.identifier().to_bech32_str(),
+            owner_private_key_file.path(),
+        ),
+    )
+    .expect("cannot decode signed stake delegation");
+
+    let owner_address = Address(
+        Discrimination::Test,
+        Kind::Account(owner.identifier().into_public_key()),
+    );
+    let ledger = Block0ConfigurationBuilder::new()
+        .with_funds(vec![Initial::Fund(vec![InitialUTxO {
+            address: owner_address.into(),
+            value: 1_000.into(),
+        }])])
+        .with_certs(vec![
+            Initial::Cert(signed_registration),
+            Initial::Cert(signed_delegation),
+        ])
+        .build()
+        .to_ledger()
+        .expect("cannot apply jcli certificates to the ledger");
+
+    let state = ledger
+        .accounts()
+        .get_state(&owner.identifier().into_public_key().into())
+        .expect("owner account not in ledger");
+    match state.delegation() {
+        DelegationType::Full(pool_id) => assert_eq!(pool_id.to_string(), stake_pool_id.trim()),
+        other => panic!(
+            "expected full delegation to the stake pool, got {:?}",
+            other
+        ),
+    }
+}
@@ -1,2 +1,3 @@
 pub mod e2e;
 pub mod retirement;
+pub mod retry;
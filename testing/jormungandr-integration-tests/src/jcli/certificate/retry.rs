@@ -0,0 +1,46 @@
+use crate::common::{configuration, process_utils};
+use std::{io, process::Command, time::Duration};
+
+const ATTEMPTS: u32 = 3;
+const BACKOFF: Duration = Duration::from_millis(10);
+
+#[test]
+pub fn test_transient_spawn_failure_is_retried() {
+    let mut calls = 0;
+    let output = process_utils::retry_on_io_error(ATTEMPTS, BACKOFF, || {
+        calls += 1;
+        if calls == 1 {
+            Err(io::Error::new(
+                io::ErrorKind::Other,
+                "transient spawn failure",
+            ))
+        } else {
+            Command::new(configuration::get_jcli_app())
+                .arg("--version")
+                .output()
+        }
+    })
+    .expect("jcli should run after a retry");
+
+    assert_eq!(calls, 2, "number of attempts");
+    assert!(output.status.success(), "jcli --version failed");
+}
+
+#[test]
+pub fn test_jcli_validation_error_is_not_retried() {
+    let mut calls = 0;
+    let output = process_utils::retry_on_io_error(ATTEMPTS, BACKOFF, || {
+        calls += 1;
+        Command::new(configuration::get_jcli_app())
+            .arg("certificate")
+            .arg("new")
+            .arg("stake-delegation")
+            .arg("not_a_public_key")
+            .arg("not_a_stake_pool_id")
+            .output()
+    })
+    .expect("jcli should run");
+
+    assert_eq!(calls, 1, "number of attempts");
+    assert!(!output.status.success(), "jcli accepted invalid input");
+}
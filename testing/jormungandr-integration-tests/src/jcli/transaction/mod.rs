@@ -1,3 +1,4 @@
 pub mod e2e;
 pub mod finalize;
 pub mod input;
+pub mod seal;
@@ -0,0 +1,38 @@
+use crate::common::jcli_wrapper::jcli_transaction_wrapper::JCLITransactionWrapper;
+use crate::common::startup;
+use jormungandr_lib::crypto::hash::Hash;
+
+const FAKE_GENESIS_HASH: &str = "19c9852ca0a68f15d0f7de5d1a26acd67a3a3251640c6066bdb91d22e2000193";
+
+lazy_static! {
+    static ref FAKE_INPUT_TRANSACTION_ID: Hash = {
+        "19c9852ca0a68f15d0f7de5d1a26acd67a3a3251640c6066bdb91d22e2000193"
+            .parse()
+            .unwrap()
+    };
+}
+
+#[test]
+pub fn test_transaction_with_missing_witness_is_not_sealed() {
+    let receiver = startup::create_new_utxo_address();
+
+    let mut transaction_wrapper = JCLITransactionWrapper::new_transaction(FAKE_GENESIS_HASH);
+    let transaction_wrapper = transaction_wrapper
+        .assert_add_input(&FAKE_INPUT_TRANSACTION_ID, 0, 100.into())
+        .assert_add_input(&FAKE_INPUT_TRANSACTION_ID, 1, 100.into())
+        .assert_add_output(&receiver.address().to_string(), 200.into())
+        .assert_finalize();
+
+    let witness = transaction_wrapper.create_witness_default("utxo", None);
+    let error = transaction_wrapper
+        .assert_make_witness(&witness)
+        .assert_add_witness(&witness)
+        .seal()
+        .err()
+        .expect("transaction with a missing witness should not be sealed");
+
+    assert_eq!(
+        error.to_string(),
+        "cannot seal transaction: expected 2 witness(es), one per input, but 1 were added"
+    );
+}
@@ -0,0 +1,133 @@
+use crate::common::configuration::Block0ConfigurationBuilder;
+use chain_crypto::{Ed25519, KeyPair};
+use chain_impl_mockchain::chaintypes::ConsensusVersion;
+use jormungandr_lib::{
+    interfaces::{Block0Configuration, ConsensusLeaderId, Initial, Ratio, RewardParams},
+    time::SecondsSinceUnixEpoch,
+};
+use jormungandr_testing_utils::testing::Block0ConfigurationExtension;
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+use std::{num::NonZeroU32, time::Duration};
+
+#[test]
+pub fn test_block0_keeps_bft_leaders_in_supplied_order() {
+    let leaders: Vec<ConsensusLeaderId> = (1..=3)
+        .map(|seed| {
+            let key_pair: KeyPair<Ed25519> =
+                KeyPair::generate(&mut ChaChaRng::from_seed([seed; 32]));
+            key_pair.public_key().clone().into()
+        })
+        .rev()
+        .collect();
+
+    let block0_configuration = Block0ConfigurationBuilder::new()
+        .with_block0_consensus(ConsensusVersion::GenesisPraos)
+        .with_bft_leaders_ordered(leaders.clone())
+        .build();
+    let decoded = Block0Configuration::from_block(&block0_configuration.to_block())
+        .expect("cannot decode block0");
+
+    assert_eq!(
+        decoded.blockchain_configuration.block0_consensus,
+        ConsensusVersion::Bft
+    );
+    assert_eq!(
+        decoded.blockchain_configuration.consensus_leader_ids,
+        leaders
+    );
+}
+
+#[test]
+pub fn test_minimal_block0_funds_the_faucet() {
+    let (block0_configuration, faucet) =
+        Block0ConfigurationBuilder::minimal_with_faucet(1_000.into());
+
+    assert_eq!(
+        block0_configuration
+            .blockchain_configuration
+            .consensus_leader_ids
+            .len(),
+        1
+    );
+    let funds: Vec<_> = block0_configuration
+        .initial
+        .iter()
+        .filter_map(|initial| match initial {
+            Initial::Fund(utxos) => Some(utxos),
+            _ => None,
+        })
+        .flatten()
+        .collect();
+    assert_eq!(funds.len(), 1);
+    assert_eq!(funds[0].address, faucet.address());
+    assert_eq!(funds[0].value, 1_000.into());
+}
+
+#[test]
+pub fn test_block0_date_offset_is_in_the_past() {
+    let block0_configuration = Block0ConfigurationBuilder::new()
+        .with_block0_date_offset(Duration::from_secs(10))
+        .build();
+
+    let block0_date = block0_configuration
+        .blockchain_configuration
+        .block0_date
+        .to_secs();
+    let now = SecondsSinceUnixEpoch::now().to_secs();
+    assert!(
+        block0_date <= now - 10,
+        "block0 date {} is not 10 seconds before {}",
+        block0_date,
+        now
+    );
+}
+
+#[test]
+pub fn test_block0_reward_params_fill_the_reward_pot() {
+    let block0_configuration = Block0ConfigurationBuilder::new()
+        .with_reward_params(
+            5_000_000.into(),
+            RewardParams::Halving {
+                constant: 1_000,
+                ratio: Ratio::new_checked(1, 2).unwrap(),
+                epoch_start: 1,
+                epoch_rate: NonZeroU32::new(10).unwrap(),
+            },
+        )
+        .build();
+
+    let ledger = block0_configuration
+        .to_ledger()
+        .expect("cannot initialize ledger from block0");
+
+    assert_eq!(ledger.remaining_rewards().0, 5_000_000, "reward pot");
+    assert_eq!(ledger.treasury_value().0, 1_000_000, "treasury");
+}
+
+#[test]
+pub fn test_block0_utxo_distribution_funds_every_address() {
+    let block0_configuration = Block0ConfigurationBuilder::new()
+        .with_utxo_distribution(1_000, 10.into(), &mut ChaChaRng::from_seed([5; 32]))
+        .build();
+
+    let funds: Vec<_> = block0_configuration
+        .initial
+        .iter()
+        .filter_map(|initial| match initial {
+            Initial::Fund(utxos) => Some(utxos),
+            _ => None,
+        })
+        .flatten()
+        .collect();
+    assert_eq!(funds.len(), 1_000, "funded outputs");
+    let total: u64 = funds
+        .iter()
+        .map(|utxo| chain_impl_mockchain::value::Value::from(utxo.value).0)
+        .sum();
+    assert_eq!(total, 10_000, "total funds");
+
+    block0_configuration
+        .to_ledger()
+        .expect("cannot initialize ledger from block0");
+}
@@ -1 +1,2 @@
+pub mod block0;
 pub mod start_node;
@@ -1,8 +1,13 @@
-use crate::common::jormungandr::{ConfigurationBuilder, Starter};
-use jormungandr_lib::interfaces::{Log, LogEntry, LogOutput};
+use crate::common::{
+    jormungandr::{ConfigurationBuilder, ConfigurationError, Starter},
+    startup,
+};
+use chain_addr::Discrimination;
+use jormungandr_lib::interfaces::{InitialUTxO, Log, LogEntry, LogOutput};
 
 use assert_fs::prelude::*;
 use assert_fs::TempDir;
+use std::time::Duration;
 
 #[test]
 pub fn test_jormungandr_leader_node_starts_successfully() {
@@ -91,3 +96,45 @@ pub fn test_jormungandr_without_logger_starts_successfully() {
     let config = ConfigurationBuilder::new().without_log().build(&temp_dir);
     let _jormungandr = Starter::new().config(config).start().unwrap();
 }
+
+#[test]
+pub fn test_jormungandr_leader_node_reaches_block_height() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = ConfigurationBuilder::new()
+        .with_slot_duration(1)
+        .build(&temp_dir);
+    let jormungandr = Starter::new()
+        .temp_dir(temp_dir)
+        .config(config)
+        .start()
+        .unwrap();
+
+    let height =
+        startup::wait_for_block_height(&jormungandr.rest_uri(), 3, Duration::from_secs(30))
+            .expect("node did not reach block height 3");
+    assert!(height >= 3);
+    jormungandr.assert_no_errors_in_log();
+}
+
+#[test]
+pub fn test_production_block0_with_test_addresses_is_rejected_before_start() {
+    let temp_dir = TempDir::new().unwrap();
+    let wallet = startup::create_new_account_address();
+
+    let result = ConfigurationBuilder::new()
+        .with_discrimination(Discrimination::Production)
+        .with_funds(vec![InitialUTxO {
+            address: wallet.address(),
+            value: 100.into(),
+        }])
+        .try_build(&temp_dir);
+
+    assert!(matches!(
+        result,
+        Err(ConfigurationError::DiscriminationMismatch {
+            expected: Discrimination::Production,
+            actual: Discrimination::Test,
+            ..
+        })
+    ));
+}
@@ -1,5 +1,11 @@
-use crate::common::{jcli_wrapper, jormungandr::ConfigurationBuilder, process_utils, startup};
-use jormungandr_lib::interfaces::LeadershipLogStatus;
+use crate::common::{
+    jcli_wrapper,
+    jormungandr::{ConfigurationBuilder, RestError},
+    process_utils, startup,
+};
+use jormungandr_lib::interfaces::{ActiveSlotCoefficient, LeadershipLogStatus};
+use jormungandr_testing_utils::stake_pool::StakePool;
+use std::time::Duration;
 
 #[test]
 pub fn test_leadership_logs_parent_hash_is_correct() {
@@ -25,3 +31,68 @@ pub fn test_leadership_logs_parent_hash_is_correct() {
         }
     }
 }
+
+#[test]
+pub fn test_leaders_schedule_of_registered_stake_pool_is_not_empty() {
+    let faucet = startup::create_new_account_address();
+    let (jormungandr, stake_pools) = startup::start_stake_pool(
+        &[faucet],
+        &[],
+        ConfigurationBuilder::new()
+            .with_slots_per_epoch(20)
+            .with_consensus_genesis_praos_active_slot_coeff(ActiveSlotCoefficient::MAXIMUM),
+    )
+    .unwrap();
+
+    let pool_id = stake_pools[0].id().to_string();
+    let schedule = jormungandr
+        .rest()
+        .leaders_schedule(&pool_id)
+        .expect("cannot get leaders schedule");
+
+    assert!(
+        !schedule.is_empty(),
+        "empty leaders schedule for stake pool {}",
+        pool_id
+    );
+}
+
+#[test]
+pub fn test_leaders_schedule_of_stake_pool_not_operated_by_the_node_is_not_found() {
+    let faucet = startup::create_new_account_address();
+    let mut owner = startup::create_new_account_address();
+    let (jormungandr, _) = startup::start_stake_pool(
+        &[faucet],
+        &[owner.clone()],
+        &mut ConfigurationBuilder::new(),
+    )
+    .unwrap();
+
+    let stake_pool = StakePool::new(&owner);
+    let fragment = owner
+        .issue_pool_registration_cert(
+            &jormungandr.genesis_block_hash(),
+            &jormungandr.fees(),
+            &stake_pool,
+        )
+        .unwrap();
+    let pool_id = stake_pool.id().to_string();
+
+    startup::submit_certificate_and_confirm(
+        &jormungandr.rest_uri(),
+        fragment,
+        |view| view.stake_pools.contains(&pool_id),
+        Duration::from_secs(60),
+    )
+    .expect("stake pool registration not confirmed");
+
+    match jormungandr.rest().leaders_schedule(&pool_id) {
+        Err(RestError::RequestError(error)) => {
+            assert_eq!(error.status(), Some(reqwest::StatusCode::NOT_FOUND))
+        }
+        other => panic!(
+            "expected not found for stake pool {}, got {:?}",
+            pool_id, other
+        ),
+    }
+}
@@ -11,14 +11,20 @@ use crate::common::{
 use chain_crypto::{Curve25519_2HashDH, SumEd25519_12};
 use chain_impl_mockchain::fee::LinearFee;
 use jormungandr_lib::{
-    crypto::hash::Hash,
+    crypto::{hash::Hash, key::KeyPair},
     interfaces::{InitialUTxO, Ratio, TaxType, Value},
 };
-use jormungandr_testing_utils::wallet::Wallet;
+use jormungandr_testing_utils::{
+    stake_pool::StakePool,
+    testing::{signed_stake_pool_cert, Block0ConfigurationExtension},
+    wallet::Wallet,
+};
 
 use assert_fs::prelude::*;
 use assert_fs::TempDir;
-use std::str::FromStr;
+use rand_chacha::ChaChaRng;
+use rand_core::SeedableRng;
+use std::{str::FromStr, time::Duration};
 
 #[test]
 pub fn create_delegate_retire_stake_pool() {
@@ -222,3 +228,100 @@ pub fn retire_stake_pool(
         "stake pool should not be listed among active stake pools"
     );
 }
+
+#[test]
+pub fn test_block0_ledger_reflects_funds_and_stake_pool() {
+    let temp_dir = TempDir::new().unwrap();
+    let alice = startup::create_new_account_address();
+    let bob = startup::create_new_account_address();
+    let stake_pool = StakePool::new(&alice);
+
+    let config = ConfigurationBuilder::new()
+        .with_funds(vec![
+            InitialUTxO {
+                address: alice.address(),
+                value: 100.into(),
+            },
+            InitialUTxO {
+                address: bob.address(),
+                value: 200.into(),
+            },
+        ])
+        .with_certs(vec![signed_stake_pool_cert(&stake_pool).into()])
+        .build(&temp_dir);
+
+    let ledger = config
+        .block0_configuration()
+        .to_ledger()
+        .expect("cannot initialize ledger from block0");
+
+    for (wallet, value) in &[(alice, 100u64), (bob, 200u64)] {
+        let state = ledger
+            .accounts()
+            .get_state(&wallet.identifier().into_public_key().into())
+            .expect("account not in block0 ledger");
+        assert_eq!(state.value().0, *value, "account value");
+    }
+    assert!(
+        ledger.delegation().lookup(&stake_pool.id()).is_some(),
+        "stake pool not registered in block0 ledger"
+    );
+}
+
+#[test]
+pub fn test_stake_pool_from_fixed_keys_has_reproducible_id() {
+    let owner = startup::create_new_account_address();
+    let stake_pool = |seed: [u8; 32]| {
+        let mut rng = ChaChaRng::from_seed(seed);
+        let kes = KeyPair::<SumEd25519_12>::generate(&mut rng);
+        let vrf = KeyPair::<Curve25519_2HashDH>::generate(&mut rng);
+        StakePool::from_keys(&owner, kes, vrf)
+    };
+
+    assert_eq!(
+        stake_pool([1; 32]).id(),
+        stake_pool([1; 32]).id(),
+        "same keys should give the same pool id"
+    );
+    assert_ne!(
+        stake_pool([1; 32]).id(),
+        stake_pool([2; 32]).id(),
+        "different keys should give different pool ids"
+    );
+}
+
+#[test]
+pub fn test_registered_stake_pool_is_confirmed_in_stake_pools() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut owner = startup::create_new_account_address();
+    let stake_pool = StakePool::new(&owner);
+
+    let config = ConfigurationBuilder::new()
+        .with_funds(vec![InitialUTxO {
+            address: owner.address(),
+            value: 1_000_000.into(),
+        }])
+        .build(&temp_dir);
+    let jormungandr = Starter::new()
+        .temp_dir(temp_dir)
+        .config(config)
+        .start()
+        .unwrap();
+
+    let fragment = owner
+        .issue_pool_registration_cert(
+            &jormungandr.genesis_block_hash(),
+            &jormungandr.fees(),
+            &stake_pool,
+        )
+        .unwrap();
+    let stake_pool_id = stake_pool.id().to_string();
+
+    startup::submit_certificate_and_confirm(
+        &jormungandr.rest_uri(),
+        fragment,
+        |view| view.stake_pools.contains(&stake_pool_id),
+        Duration::from_secs(60),
+    )
+    .expect("stake pool registration not confirmed");
+}
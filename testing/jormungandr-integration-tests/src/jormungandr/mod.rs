@@ -2,6 +2,8 @@ pub mod bft;
 pub mod explorer;
 pub mod genesis;
 pub mod legacy;
+pub mod node_config;
 pub mod recovery;
+pub mod rest;
 pub mod transactions;
 pub mod vit;
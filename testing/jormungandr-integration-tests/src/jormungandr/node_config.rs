@@ -0,0 +1,46 @@
+use crate::common::configuration::{assert_node_config_round_trips, NodeConfigBuilder};
+use jormungandr_lib::{
+    interfaces::{Log, LogEntry, LogOutput, Mempool, Policy},
+    time::Duration,
+};
+use std::path::PathBuf;
+
+#[test]
+pub fn test_default_node_config_round_trips() {
+    assert_node_config_round_trips(&NodeConfigBuilder::new().build());
+}
+
+#[test]
+pub fn test_node_config_with_optional_sections_round_trips() {
+    let node_config = NodeConfigBuilder::new()
+        .with_explorer()
+        .with_account_history(16)
+        .with_storage(PathBuf::from("storage"))
+        .with_mempool(Mempool::default())
+        .with_log(Log(vec![LogEntry {
+            level: "info".to_string(),
+            format: "json".to_string(),
+            output: LogOutput::Stderr,
+        }]))
+        .build();
+
+    assert_node_config_round_trips(&node_config);
+}
+
+#[test]
+pub fn test_node_config_with_p2p_settings_round_trips() {
+    let trusted_peer = NodeConfigBuilder::new()
+        .build()
+        .p2p
+        .make_trusted_peer_setting();
+    let node_config = NodeConfigBuilder::new()
+        .with_listen_address("/ip4/127.0.0.1/tcp/8299".to_string())
+        .with_trusted_peers(vec![trusted_peer])
+        .with_policy(Policy {
+            quarantine_duration: Some(Duration::new(30, 0)),
+            quarantine_whitelist: Some(vec!["/ip4/127.0.0.1/tcp/8300".parse().unwrap()]),
+        })
+        .build();
+
+    assert_node_config_round_trips(&node_config);
+}
@@ -0,0 +1,53 @@
+use crate::common::{
+    jormungandr::{ConfigurationBuilder, Starter},
+    startup,
+};
+use jormungandr_lib::interfaces::InitialUTxO;
+
+use assert_fs::TempDir;
+
+#[test]
+pub fn test_account_history_lists_each_funding_transaction() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut faucet = startup::create_new_account_address();
+    let receiver = startup::create_new_account_address();
+
+    let config = ConfigurationBuilder::new()
+        .with_funds(vec![InitialUTxO {
+            address: faucet.address(),
+            value: 1_000.into(),
+        }])
+        .with_account_history(16)
+        .build(&temp_dir);
+    let jormungandr = Starter::new()
+        .temp_dir(temp_dir)
+        .config(config)
+        .start()
+        .unwrap();
+
+    let mut fragment_ids = Vec::new();
+    for _ in 0..2 {
+        let fragment_id = startup::faucet::fund(
+            &jormungandr.rest_uri(),
+            &mut faucet,
+            receiver.address(),
+            100.into(),
+        )
+        .expect("cannot fund receiver");
+        fragment_ids.push(fragment_id);
+    }
+
+    let history = jormungandr
+        .rest()
+        .account_history(&receiver.identifier().to_hex())
+        .expect("cannot get account history");
+
+    assert_eq!(history.len(), 2, "history entries");
+    for (entry, fragment_id) in history.iter().zip(fragment_ids.iter()) {
+        assert_eq!(entry.fragment_id, (*fragment_id).into(), "fragment id");
+    }
+    assert!(
+        history[0].chain_length <= history[1].chain_length,
+        "history should be ordered from the oldest to the newest"
+    );
+}
@@ -0,0 +1,51 @@
+use crate::common::{
+    jormungandr::{ConfigurationBuilder, Starter},
+    startup,
+};
+use chain_addr::Discrimination;
+use chain_impl_mockchain::fee::LinearFee;
+use jormungandr_testing_utils::testing::Block0ConfigurationExtension;
+
+use assert_fs::TempDir;
+
+#[test]
+pub fn test_block0_settings_match_block0_configuration() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = ConfigurationBuilder::new()
+        .with_linear_fees(LinearFee::new(1, 2, 3))
+        .build(&temp_dir);
+    let jormungandr = Starter::new()
+        .temp_dir(temp_dir)
+        .config(config.clone())
+        .start()
+        .unwrap();
+
+    let block0_settings = jormungandr
+        .rest()
+        .block0_settings()
+        .expect("cannot get block0 settings");
+    let expected = &config.block0_configuration().blockchain_configuration;
+
+    assert_eq!(block0_settings.discrimination, Discrimination::Test);
+    assert_eq!(block0_settings.discrimination, expected.discrimination);
+    assert_eq!(block0_settings.linear_fees, expected.linear_fees);
+}
+
+#[test]
+pub fn test_computed_genesis_hash_matches_node_block0_hash() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = ConfigurationBuilder::new().build(&temp_dir);
+    let jormungandr = Starter::new()
+        .temp_dir(temp_dir)
+        .config(config.clone())
+        .start()
+        .unwrap();
+
+    startup::assert_block0_hash_matches(&jormungandr.rest_uri(), config.block0_configuration());
+
+    let expected_hash = config.block0_configuration().genesis_hash();
+    assert_eq!(
+        jormungandr.genesis_block_hash().to_string(),
+        expected_hash.to_string()
+    );
+}
@@ -1,8 +1,22 @@
 use crate::common::{
-    jcli_wrapper, jormungandr::ConfigurationBuilder, startup, transaction_utils::TransactionHash,
+    jcli_wrapper,
+    jormungandr::{ConfigurationBuilder, Starter},
+    process_utils, startup,
+    transaction_utils::TransactionHash,
 };
-use chain_impl_mockchain::fee::LinearFee;
-use jormungandr_lib::interfaces::{ActiveSlotCoefficient, Mempool, Value};
+use assert_fs::TempDir;
+use chain_addr::Discrimination;
+use chain_core::property::{Deserialize as _, Serialize as _};
+use chain_impl_mockchain::{block::Block, fee::LinearFee};
+use jormungandr_lib::{
+    crypto::{account::SigningKey, hash::Hash},
+    interfaces::{ActiveSlotCoefficient, FragmentStatusDto, InitialUTxO, Mempool, Value},
+};
+use jormungandr_testing_utils::{
+    testing::{double_spend_pair, FragmentBuilderError},
+    wallet::{ChangeStrategy, SigningAlgorithm, Wallet, WalletError},
+};
+use std::time::{Duration, Instant};
 
 #[test]
 pub fn accounts_funds_are_updated_after_transaction() {
@@ -83,3 +97,323 @@ pub fn accounts_funds_are_updated_after_transaction() {
         "receiver value after transaction"
     );
 }
+
+#[test]
+pub fn account_transaction_with_explicit_counter_is_accepted() {
+    let receiver = startup::create_new_account_address();
+    let sender = startup::create_new_account_address();
+    let fee = LinearFee::new(1, 1, 1);
+
+    let (jormungandr, _) = startup::start_stake_pool(
+        &[sender.clone()],
+        &[receiver.clone()],
+        ConfigurationBuilder::new()
+            .with_slots_per_epoch(20)
+            .with_consensus_genesis_praos_active_slot_coeff(ActiveSlotCoefficient::MAXIMUM)
+            .with_slot_duration(3)
+            .with_linear_fees(fee.clone()),
+    )
+    .unwrap();
+
+    let counter_before = jcli_wrapper::assert_rest_account_get_stats(
+        &sender.address().to_string(),
+        &jormungandr.rest_uri(),
+    )
+    .counter();
+
+    let transaction_fee: Value = (fee.constant + fee.coefficient * 2).into();
+    let new_transaction = sender
+        .account_transaction(
+            &jormungandr.genesis_block_hash(),
+            receiver.address(),
+            1.into(),
+            transaction_fee,
+            counter_before,
+        )
+        .unwrap()
+        .encode();
+
+    jcli_wrapper::assert_transaction_in_block(&new_transaction, &jormungandr);
+
+    let counter_after = jcli_wrapper::assert_rest_account_get_stats(
+        &sender.address().to_string(),
+        &jormungandr.rest_uri(),
+    )
+    .counter();
+
+    assert_eq!(counter_before + 1, counter_after, "spending counter");
+}
+
+#[test]
+pub fn account_transaction_with_overflowing_value_is_rejected() {
+    let receiver = startup::create_new_account_address();
+    let sender = startup::create_new_account_address();
+
+    let result = sender.account_transaction(
+        &Hash::from([0; 32]),
+        receiver.address(),
+        u64::MAX.into(),
+        1.into(),
+        0,
+    );
+
+    assert!(matches!(
+        result,
+        Err(WalletError::InputValueOverflow { .. })
+    ));
+}
+
+#[test]
+pub fn utxo_transaction_change_goes_to_new_address() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut sender = startup::create_new_utxo_address();
+    let receiver = startup::create_new_account_address();
+    let fee = LinearFee::new(1, 1, 1);
+
+    let config = ConfigurationBuilder::new()
+        .with_funds(vec![InitialUTxO {
+            address: sender.address(),
+            value: 100.into(),
+        }])
+        .with_linear_fees(fee.clone())
+        .build(&temp_dir);
+    let jormungandr = Starter::new()
+        .temp_dir(temp_dir)
+        .config(config.clone())
+        .start()
+        .unwrap();
+    let utxo = config.block0_utxo_for_address(&sender);
+
+    let new_transaction = sender
+        .utxo_transaction(
+            &jormungandr.genesis_block_hash(),
+            &jormungandr.fees(),
+            &utxo,
+            receiver.address(),
+            10.into(),
+            ChangeStrategy::NewAddress,
+        )
+        .unwrap()
+        .encode();
+
+    let fragment_id = jcli_wrapper::assert_transaction_in_block(&new_transaction, &jormungandr);
+
+    let new_address = match &sender {
+        Wallet::UTxO(utxo_wallet) => utxo_wallet.last_address(Discrimination::Test),
+        _ => unreachable!(),
+    };
+    assert_ne!(new_address, sender.address(), "change address is not new");
+
+    // the receiver output comes first, followed by the change output
+    let change =
+        jcli_wrapper::assert_rest_utxo_get(&jormungandr.rest_uri(), &fragment_id.to_string(), 1);
+    let transaction_fee = fee.constant + fee.coefficient * 3;
+    assert_eq!(*change.address(), new_address, "change address");
+    assert_eq!(
+        *change.associated_fund(),
+        (100 - 10 - transaction_fee).into(),
+        "change value"
+    );
+}
+
+#[test]
+pub fn faucet_funds_new_account() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut faucet = startup::create_new_account_address();
+    let receiver = startup::create_new_account_address();
+
+    let config = ConfigurationBuilder::new()
+        .with_funds(vec![InitialUTxO {
+            address: faucet.address(),
+            value: 1_000.into(),
+        }])
+        .build(&temp_dir);
+    let jormungandr = Starter::new()
+        .temp_dir(temp_dir)
+        .config(config)
+        .start()
+        .unwrap();
+
+    startup::faucet::fund(
+        &jormungandr.rest_uri(),
+        &mut faucet,
+        receiver.address(),
+        100.into(),
+    )
+    .expect("cannot fund receiver");
+
+    let receiver_state = jcli_wrapper::assert_rest_account_get_stats(
+        &receiver.address().to_string(),
+        &jormungandr.rest_uri(),
+    );
+    assert_eq!(*receiver_state.value(), 100.into(), "receiver value");
+}
+
+#[test]
+pub fn fragment_status_reports_transaction_in_block() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut sender = startup::create_new_account_address();
+    let receiver = startup::create_new_account_address();
+
+    let config = ConfigurationBuilder::new()
+        .with_funds(vec![InitialUTxO {
+            address: sender.address(),
+            value: 1_000.into(),
+        }])
+        .build(&temp_dir);
+    let jormungandr = Starter::new()
+        .temp_dir(temp_dir)
+        .config(config)
+        .start()
+        .unwrap();
+
+    let transaction = sender
+        .transaction_to(
+            &jormungandr.genesis_block_hash(),
+            &jormungandr.fees(),
+            receiver.address(),
+            100.into(),
+        )
+        .unwrap();
+    let rest = jormungandr.rest();
+    let fragment_id = rest
+        .send_fragment(transaction)
+        .expect("cannot send transaction")
+        .fragment_id()
+        .clone();
+
+    let start = Instant::now();
+    let (block, chain_length) = loop {
+        match rest.fragment_status(&fragment_id).unwrap() {
+            FragmentStatusDto::InABlock {
+                block,
+                chain_length,
+                ..
+            } => break (block, chain_length),
+            FragmentStatusDto::Rejected { reason } => {
+                panic!("transaction '{}' rejected: {}", fragment_id, reason)
+            }
+            FragmentStatusDto::Pending => (),
+        }
+        assert!(
+            start.elapsed() < Duration::from_secs(60),
+            "transaction '{}' not in a block after 60 seconds",
+            fragment_id
+        );
+        process_utils::sleep(1);
+    };
+
+    let block_hex =
+        jcli_wrapper::assert_rest_get_block_by_id(&block.to_string(), &jormungandr.rest_uri());
+    let block = Block::deserialize(hex::decode(&block_hex).unwrap().as_slice()).unwrap();
+    assert_eq!(
+        chain_length,
+        u32::from(block.header.chain_length()),
+        "chain length of the block including the transaction"
+    );
+}
+
+#[test]
+pub fn double_spending_utxo_transaction_is_rejected() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut sender = startup::create_new_utxo_address();
+    let receiver = startup::create_new_account_address();
+
+    let config = ConfigurationBuilder::new()
+        .with_funds(vec![InitialUTxO {
+            address: sender.address(),
+            value: 100.into(),
+        }])
+        .build(&temp_dir);
+    let jormungandr = Starter::new()
+        .temp_dir(temp_dir)
+        .config(config.clone())
+        .start()
+        .unwrap();
+    let utxo = config.block0_utxo_for_address(&sender);
+
+    let (transaction, double_spend) = double_spend_pair(
+        &mut sender,
+        &jormungandr.genesis_block_hash(),
+        &utxo,
+        (receiver.address(), 10.into()),
+        (receiver.address(), 20.into()),
+        &jormungandr.fees(),
+    )
+    .unwrap();
+    jcli_wrapper::assert_transaction_in_block(&transaction.encode(), &jormungandr);
+
+    // the utxo was consumed by the first transaction
+    startup::assert_fragment_rejected(
+        &jormungandr.rest_uri(),
+        &double_spend.serialize_as_vec().unwrap(),
+        "Invalid UTxO",
+    );
+}
+
+#[test]
+pub fn transaction_witnessed_with_default_signing_algorithm_is_applied() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut sender = startup::create_new_account_address();
+    let receiver = startup::create_new_account_address();
+
+    let config = ConfigurationBuilder::new()
+        .with_funds(vec![InitialUTxO {
+            address: sender.address(),
+            value: 100.into(),
+        }])
+        .build(&temp_dir);
+    let jormungandr = Starter::new()
+        .temp_dir(temp_dir)
+        .config(config)
+        .start()
+        .unwrap();
+
+    let transaction = sender
+        .transaction_to_signed_with(
+            &jormungandr.genesis_block_hash(),
+            &jormungandr.fees(),
+            receiver.address(),
+            10.into(),
+            SigningAlgorithm::default(),
+        )
+        .unwrap()
+        .encode();
+    jcli_wrapper::assert_transaction_in_block(&transaction, &jormungandr);
+
+    let receiver_state = jcli_wrapper::assert_rest_account_get_stats(
+        &receiver.address().to_string(),
+        &jormungandr.rest_uri(),
+    );
+    assert_eq!(*receiver_state.value(), 10.into(), "receiver value");
+}
+
+#[test]
+pub fn transaction_cannot_be_witnessed_with_another_signing_algorithm() {
+    let sender = startup::create_new_account_address();
+    let extended_sender = Wallet::from_existing_account(
+        &SigningKey::generate_extended(&mut rand::rngs::OsRng).to_bech32_str(),
+        None,
+    );
+    let receiver = startup::create_new_account_address();
+
+    for (mut wallet, algorithm) in vec![
+        (sender, SigningAlgorithm::Ed25519Extended),
+        (extended_sender, SigningAlgorithm::Ed25519),
+    ] {
+        let result = wallet.transaction_to_signed_with(
+            &Hash::from([0; 32]),
+            &LinearFee::new(0, 0, 0),
+            receiver.address(),
+            10.into(),
+            algorithm,
+        );
+
+        match result {
+            Err(WalletError::FragmentError(
+                FragmentBuilderError::IncompatibleSigningAlgorithm(rejected),
+            )) => assert_eq!(rejected, algorithm),
+            _ => panic!("witness signed with {:?} not rejected", algorithm),
+        }
+    }
+}
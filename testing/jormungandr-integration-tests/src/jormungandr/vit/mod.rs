@@ -4,7 +4,7 @@ use crate::common::{
 };
 use assert_fs::TempDir;
 use chain_impl_mockchain::{testing::VoteTestGen, vote::CommitteeId};
-use jormungandr_lib::interfaces::CommitteeIdDef;
+use jormungandr_lib::{crypto::hash::Hash, interfaces::CommitteeIdDef};
 
 fn committee_ids() -> Vec<CommitteeIdDef> {
     vec![
@@ -61,3 +61,51 @@ pub fn test_get_initial_vote_plan() {
 
     assert_eq!(actual_vote_plan_id, expected_vote_plan.to_id().to_string());
 }
+
+#[test]
+pub fn test_initial_vote_plan_proposals_via_rest() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let expected_vote_plan = VoteTestGen::vote_plan();
+    let vote_plan_cert =
+        jormungandr_testing_utils::testing::vote_plan_cert(&expected_vote_plan).into();
+
+    let config = ConfigurationBuilder::new()
+        .with_committee_ids(committee_ids())
+        .with_certs(vec![vote_plan_cert])
+        .build(&temp_dir);
+
+    let jormungandr = Starter::new()
+        .temp_dir(temp_dir)
+        .config(config)
+        .start()
+        .unwrap();
+
+    let vote_plans = jormungandr
+        .rest()
+        .vote_plans()
+        .expect("cannot get active vote plans");
+    assert_eq!(vote_plans.len(), 1);
+
+    let vote_plan = vote_plans.get(0).unwrap();
+    assert_eq!(
+        vote_plan.voteplan_id,
+        Hash::from(expected_vote_plan.to_id())
+    );
+    assert_eq!(
+        vote_plan.proposals.len(),
+        expected_vote_plan.proposals().len()
+    );
+    for (index, (proposal, expected)) in vote_plan
+        .proposals
+        .iter()
+        .zip(expected_vote_plan.proposals().iter())
+        .enumerate()
+    {
+        assert_eq!(proposal.index as usize, index);
+        assert_eq!(
+            proposal.external_id,
+            Hash::from(expected.external_id().clone())
+        );
+    }
+}
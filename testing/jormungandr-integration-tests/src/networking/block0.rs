@@ -0,0 +1,250 @@
+use crate::common::{
+    jcli_wrapper,
+    network::{self, wallet, Controller, ControllerError, WalletTemplateBuilder},
+};
+use chain_impl_mockchain::{
+    account::DelegationType,
+    certificate::{PoolId, SignedCertificate},
+};
+use jormungandr_lib::interfaces::{Initial, InitialUTxO, Value};
+use jormungandr_testing_utils::testing::{
+    network_builder::{Seed, SettingsError},
+    Block0ConfigurationExtension,
+};
+
+const LEADER: &str = "LEADER";
+const PASSIVE: &str = "PASSIVE";
+
+#[test]
+pub fn funded_accounts_are_seeded_in_block0() {
+    const COUNT: usize = 50;
+    const VALUE: u64 = 1_000;
+
+    let mut wallets = WalletTemplateBuilder::accounts("account", COUNT, VALUE);
+    wallets[0].delegated_to(LEADER);
+    let mut network_controller = network::builder()
+        .star_topology(LEADER, vec![])
+        .initials(wallets.iter_mut().collect())
+        .build()
+        .unwrap();
+
+    let funds: Vec<InitialUTxO> = network_controller
+        .block0_configuration()
+        .initial
+        .iter()
+        .filter_map(|initial| match initial {
+            Initial::Fund(funds) => Some(funds.clone()),
+            _ => None,
+        })
+        .flatten()
+        .collect();
+
+    assert_eq!(funds.len(), COUNT, "number of funded accounts in block0");
+    let total: u64 = funds.iter().map(|fund| -> u64 { fund.value.into() }).sum();
+    assert_eq!(total, VALUE * COUNT as u64, "total value in block0");
+
+    for i in 0..COUNT {
+        let alias = format!("account{}", i);
+        let wallet = network_controller.wallet(&alias).unwrap();
+        assert!(
+            funds.iter().any(|fund| fund.address == wallet.address()),
+            "no funds in block0 for {}",
+            alias
+        );
+    }
+}
+
+#[test]
+pub fn genesis_praos_block0_registers_a_stake_pool_and_builds_a_ledger() {
+    let network_controller = network::builder()
+        .single_trust_direction(PASSIVE, LEADER)
+        .initials(vec![wallet("delegated")
+            .with(1_000_000)
+            .delegated_to(LEADER)])
+        .build()
+        .unwrap();
+
+    let block0 = network_controller.block0_configuration();
+    assert!(
+        block0.initial.iter().any(|initial| match initial {
+            Initial::Cert(cert) => matches!(cert.0, SignedCertificate::PoolRegistration(..)),
+            _ => false,
+        }),
+        "no stake pool registration in block0"
+    );
+    block0
+        .to_ledger()
+        .expect("the ledger should accept the genesis praos block0");
+}
+
+#[test]
+pub fn genesis_praos_network_without_stake_pool_is_rejected() {
+    let result = network::builder()
+        .single_trust_direction(PASSIVE, LEADER)
+        .initials(vec![wallet("alice").with(1_000_000)])
+        .build();
+
+    assert!(matches!(
+        result,
+        Err(ControllerError::Settings(SettingsError::NoStakePool))
+    ));
+}
+
+#[test]
+pub fn node_with_two_stake_pools_registers_both_in_block0() {
+    let network_controller = network::builder()
+        .single_trust_direction(PASSIVE, LEADER)
+        .stake_pools(LEADER, 2)
+        .initials(vec![wallet("delegated")
+            .with(1_000_000)
+            .delegated_to(LEADER)])
+        .build()
+        .unwrap();
+
+    let registered_pool_ids: Vec<PoolId> = network_controller
+        .block0_configuration()
+        .initial
+        .iter()
+        .filter_map(|initial| match initial {
+            Initial::Cert(cert) => match &cert.0 {
+                SignedCertificate::PoolRegistration(registration, _) => Some(registration.to_id()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    assert_eq!(registered_pool_ids.len(), 2, "stake pool registrations");
+
+    let node_setting = network_controller.node_settings(LEADER).unwrap();
+    let node_pool_ids: Vec<PoolId> = std::iter::once(node_setting.secrets())
+        .chain(node_setting.additional_secrets().iter())
+        .map(|secret| {
+            secret
+                .genesis
+                .as_ref()
+                .expect("stake pool secret")
+                .node_id
+                .clone()
+                .into_digest_of()
+        })
+        .collect();
+
+    assert_eq!(node_pool_ids.len(), 2, "stake pool secrets of the node");
+    for pool_id in &node_pool_ids {
+        assert!(
+            registered_pool_ids.contains(pool_id),
+            "pool {} from node secret is not registered in block0",
+            pool_id
+        );
+    }
+}
+
+#[test]
+pub fn wallet_delegating_by_ratio_splits_stake_across_node_pools() {
+    let network_controller = network::builder()
+        .single_trust_direction(PASSIVE, LEADER)
+        .initials(vec![wallet("delegated")
+            .with(1_000_000)
+            .delegated_to_many(vec![(LEADER, 70), (PASSIVE, 30)])])
+        .build()
+        .unwrap();
+
+    let node_pool_id = |alias: &str| -> PoolId {
+        network_controller
+            .node_settings(alias)
+            .unwrap()
+            .secrets()
+            .genesis
+            .as_ref()
+            .expect("stake pool secret")
+            .node_id
+            .clone()
+            .into_digest_of()
+    };
+
+    let ratios: Vec<Vec<(PoolId, u8)>> = network_controller
+        .block0_configuration()
+        .initial
+        .iter()
+        .filter_map(|initial| match initial {
+            Initial::Cert(cert) => match &cert.0 {
+                SignedCertificate::StakeDelegation(delegation, _) => {
+                    match delegation.get_delegation_type() {
+                        DelegationType::Ratio(ratio) => Some(ratio.pools().to_vec()),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(ratios.len(), 1, "split delegation certificates in block0");
+    let pools = &ratios[0];
+    assert_eq!(pools.len(), 2);
+    assert!(pools.contains(&(node_pool_id(LEADER), 70)));
+    assert!(pools.contains(&(node_pool_id(PASSIVE), 30)));
+}
+
+fn seeded_network(seed: Seed) -> Controller {
+    network::builder()
+        .star_topology(LEADER, vec![])
+        .seed(seed)
+        .initials(vec![
+            wallet("delegated").with(1_000_000).delegated_to(LEADER),
+            wallet("alice").with(1_000).utxo(),
+        ])
+        .build()
+        .unwrap()
+}
+
+#[test]
+pub fn seeded_wallet_utxo_matches_node_utxo() {
+    const SEED: &str = "f3b2a1c0e9d8f7a6b5c4d3e2f1a0b9c8d7e6f5a4b3c2d1e0f9a8b7c6d5e4f3a2";
+
+    let mut network_controller = seeded_network(SEED.parse().unwrap());
+    let utxo = network_controller.wallet_utxo("alice").unwrap();
+
+    assert_eq!(
+        utxo,
+        seeded_network(SEED.parse().unwrap())
+            .wallet_utxo("alice")
+            .unwrap(),
+        "same seed should produce the same block0 outpoint"
+    );
+
+    let leader = network_controller.spawn_and_wait(LEADER);
+    let utxo_info = jcli_wrapper::assert_rest_utxo_get(
+        &leader.rest_uri(),
+        &utxo.transaction_id.to_string(),
+        utxo.output_index,
+    );
+    let alice = network_controller.wallet("alice").unwrap();
+
+    assert_eq!(*utxo_info.address(), alice.address(), "utxo address");
+    assert_eq!(
+        *utxo_info.associated_fund(),
+        Value::from(utxo.value),
+        "utxo value"
+    );
+}
+
+#[test]
+pub fn delegation_of_unfunded_wallet_is_rejected_as_orphaned() {
+    let result = network::builder()
+        .single_trust_direction(PASSIVE, LEADER)
+        .initials(vec![
+            wallet("funded").with(1_000_000).delegated_to(LEADER),
+            wallet("unfunded").with(0).delegated_to(LEADER),
+        ])
+        .build();
+
+    match result {
+        Err(ControllerError::Settings(SettingsError::OrphanedDelegations(orphans))) => {
+            assert_eq!(orphans, vec!["unfunded".to_string()])
+        }
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(_) => panic!("orphaned delegation was not rejected"),
+    }
+}
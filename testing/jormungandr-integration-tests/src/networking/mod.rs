@@ -1,4 +1,6 @@
+pub mod block0;
 pub mod communication;
 pub mod p2p;
+pub mod secrets;
 pub mod stats;
 pub mod testnet;
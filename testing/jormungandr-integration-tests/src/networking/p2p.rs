@@ -1,15 +1,18 @@
 use crate::common::{
     jormungandr::process::JormungandrProcess,
     network::{self, params, wallet},
-    process_utils,
+    process_utils, startup,
 };
 
+use chain_impl_mockchain::chaintypes::ConsensusVersion;
 use jormungandr_lib::{
     interfaces::{
         Explorer, LayersConfig, PeerRecord, Policy, PreferredListConfig, TopicsOfInterest,
     },
     time::Duration,
 };
+use std::time::Duration as StdDuration;
+
 const CLIENT: &str = "CLIENT";
 const SERVER: &str = "SERVER";
 
@@ -38,6 +41,18 @@ pub fn assert_are_in_quarantine(
     assert_record_is_present(available_list, peers, "quarantine", info)
 }
 
+pub fn assert_are_available(
+    node: &JormungandrProcess,
+    peers: Vec<&JormungandrProcess>,
+    info: &str,
+) {
+    let available_list = node
+        .rest()
+        .p2p_available()
+        .expect("cannot list available peers");
+    assert_record_is_present(available_list, peers, "available", info)
+}
+
 pub fn assert_record_is_present(
     peer_list: Vec<PeerRecord>,
     peers: Vec<&JormungandrProcess>,
@@ -123,6 +138,27 @@ pub fn assert_node_stats(
     );
 }
 
+#[test]
+pub fn connected_nodes_list_each_other_as_available() {
+    let mut network_controller = network::builder()
+        .single_trust_direction(CLIENT, SERVER)
+        .initials(vec![
+            wallet("delegated1").with(1_000_000).delegated_to(CLIENT),
+            wallet("delegated2").with(1_000_000).delegated_to(SERVER),
+        ])
+        .build()
+        .unwrap();
+
+    let server = network_controller.spawn_and_wait(SERVER);
+    let client = network_controller.spawn_and_wait(CLIENT);
+
+    // leave the nodes some time to gossip about each other
+    process_utils::sleep(10);
+
+    assert_are_available(&client, vec![&server], "client after connection");
+    assert_are_available(&server, vec![&client], "server after connection");
+}
+
 #[test]
 pub fn node_whitelist_itself() {
     let mut network_controller = network::builder()
@@ -374,3 +410,27 @@ pub fn topic_of_interest_influences_node_sync_ability() {
         "node with high block topic of interest should have more recieved blocks"
     );
 }
+
+#[test]
+pub fn bft_nodes_converge_on_the_same_tip() {
+    let mut network_controller = network::builder()
+        .consensus(ConsensusVersion::Bft)
+        .single_trust_direction(CLIENT, SERVER)
+        .initials(vec![
+            wallet("delegated1").with(1_000_000).delegated_to(CLIENT),
+            wallet("delegated2").with(1_000_000).delegated_to(SERVER),
+        ])
+        .build()
+        .unwrap();
+
+    let server = network_controller.spawn_and_wait(SERVER);
+    let client = network_controller.spawn_and_wait(CLIENT);
+
+    let server_uri = server.rest_uri();
+    let client_uri = client.rest_uri();
+    startup::wait_for_block_height(&server_uri, 5, StdDuration::from_secs(60)).unwrap();
+    startup::assert_nodes_converged(
+        &[server_uri.as_str(), client_uri.as_str()],
+        StdDuration::from_secs(60),
+    );
+}
@@ -0,0 +1,109 @@
+use crate::common::configuration::NodeConfigBuilder;
+use chain_crypto::{Ed25519, Verification};
+use chain_impl_mockchain::chaintypes::ConsensusVersion;
+use jormungandr_lib::{
+    crypto::key::SigningKey,
+    interfaces::{
+        ActiveSlotCoefficient, Bft, KESUpdateSpeed, NodeSecret, NumberOfSlotsPerEpoch, SlotDuration,
+    },
+};
+use jormungandr_testing_utils::testing::network_builder::{
+    Blockchain, Node, NodeSetting, Random, Seed, Settings, TopologyBuilder,
+};
+
+const LEADER: &str = "LEADER";
+const RELAY: &str = "RELAY";
+
+fn node_setting(bft: Option<Bft>) -> NodeSetting {
+    NodeSetting::new(
+        LEADER.to_owned(),
+        NodeConfigBuilder::new().build(),
+        NodeSecret { bft, genesis: None },
+        Node::new(LEADER),
+    )
+}
+
+#[test]
+pub fn bft_signature_verifies_against_node_identifier() {
+    let signing_key = SigningKey::<Ed25519>::generate(rand::rngs::OsRng);
+    let identifier = signing_key.identifier();
+    let node_setting = node_setting(Some(Bft { signing_key }));
+    let payload = b"jormungandr".to_vec();
+
+    let signature = node_setting
+        .sign_bft(&payload)
+        .expect("node has no bft secret");
+
+    assert_eq!(
+        signature.verify(&identifier, &payload),
+        Verification::Success,
+        "signature does not verify against node identifier"
+    );
+    assert_eq!(
+        signature.verify(&identifier, &b"other payload".to_vec()),
+        Verification::Failed
+    );
+}
+
+#[test]
+pub fn node_without_bft_secret_cannot_sign() {
+    assert!(node_setting(None)
+        .sign_bft(&b"jormungandr".to_vec())
+        .is_none());
+}
+
+#[test]
+pub fn passive_relay_has_no_secret_and_trusts_leader() {
+    let mut topology_builder = TopologyBuilder::new();
+    topology_builder.register_node(Node::new(LEADER));
+    topology_builder.register_node(Node::passive(RELAY, vec![LEADER.to_owned()]));
+
+    let mut blockchain = Blockchain::new(
+        ConsensusVersion::Bft,
+        NumberOfSlotsPerEpoch::new(60).unwrap(),
+        SlotDuration::new(2).unwrap(),
+        KESUpdateSpeed::new(46800).unwrap(),
+        ActiveSlotCoefficient::MAXIMUM,
+    );
+    blockchain.add_leader(LEADER);
+
+    let nodes = topology_builder
+        .build()
+        .into_iter()
+        .map(|(alias, template)| {
+            let secret = NodeSecret {
+                bft: None,
+                genesis: None,
+            };
+            let config = NodeConfigBuilder::new().build();
+            (
+                alias.clone(),
+                NodeSetting::new(alias, config, secret, template),
+            )
+        })
+        .collect();
+    let mut random = Random::new(Seed::generate(rand::rngs::OsRng));
+    let settings = Settings::new(nodes, blockchain, &mut random).unwrap();
+
+    let leader = &settings.nodes[LEADER];
+    let relay = &settings.nodes[RELAY];
+    assert!(leader.secrets().bft.is_some(), "leader has no bft secret");
+    assert!(relay.secrets().bft.is_none(), "relay has a bft secret");
+    assert!(
+        relay.secrets().genesis.is_none(),
+        "relay has a genesis secret"
+    );
+    assert!(relay.additional_secrets().is_empty());
+    let trusted_peers: Vec<_> = relay
+        .config()
+        .p2p
+        .trusted_peers
+        .iter()
+        .map(|peer| peer.address.clone())
+        .collect();
+    assert_eq!(
+        trusted_peers,
+        vec![leader.config().p2p.get_listen_address()]
+    );
+    assert_eq!(relay.config().skip_bootstrap, Some(false));
+}
@@ -88,12 +88,13 @@ impl ControllerBuilder {
         self.blockchain = Some(blockchain)
     }
 
-    pub fn build_settings(&mut self, context: &mut ContextChaCha) {
+    pub fn build_settings(&mut self, context: &mut ContextChaCha) -> Result<()> {
         self.controller_progress.inc(1);
         let topology = std::mem::replace(&mut self.topology, None).unwrap();
         let blockchain = std::mem::replace(&mut self.blockchain, None).unwrap();
-        self.settings = Some(Settings::prepare(topology, blockchain, context));
+        self.settings = Some(Settings::prepare(topology, blockchain, context)?);
         self.controller_progress.inc(5);
+        Ok(())
     }
 
     pub fn build(self, context: ContextChaCha) -> Result<Controller> {
@@ -30,6 +30,7 @@ error_chain! {
 
     foreign_links {
         Wallet(jormungandr_testing_utils::wallet::WalletError);
+        Settings(jormungandr_testing_utils::testing::network_builder::SettingsError);
         FsFixture(assert_fs::fixture::FixtureError);
         Io(std::io::Error);
         Reqwest(reqwest::Error);
@@ -113,7 +114,7 @@ macro_rules! prepare_scenario {
         )*
         builder.set_blockchain(blockchain);
 
-        builder.build_settings($context);
+        builder.build_settings($context)?;
 
         builder
     }};
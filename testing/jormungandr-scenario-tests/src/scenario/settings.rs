@@ -8,7 +8,7 @@ use jormungandr_lib::{
 };
 use jormungandr_testing_utils::testing::network_builder::{
     Blockchain as BlockchainTemplate, Node as NodeTemplate, NodeAlias, NodeSetting, Settings,
-    Topology as TopologyTemplate, WalletTemplate, WalletType,
+    SettingsError, Topology as TopologyTemplate, WalletTemplate, WalletType,
 };
 use rand_core::{CryptoRng, RngCore};
 use std::io::Write;
@@ -30,7 +30,7 @@ pub trait PrepareSettings {
         topology: TopologyTemplate,
         blockchain: BlockchainTemplate,
         context: &mut Context<RNG>,
-    ) -> Self
+    ) -> Result<Self, SettingsError>
     where
         RNG: RngCore + CryptoRng;
 }
@@ -147,6 +147,7 @@ impl PrepareNodeSettings for NodeSetting {
             alias,
             config: NodeConfig::prepare(context),
             secret: NodeSecret::prepare(context),
+            additional_secrets: Vec::new(),
             node_topology: template,
         }
     }
@@ -176,6 +177,7 @@ impl Prepare for NodeConfig {
             log: Some(Log::prepare(context)),
             mempool: Some(Mempool::prepare(context)),
             explorer: Explorer::prepare(context),
+            account_history: None,
             bootstrap_from_trusted_peers: None,
             skip_bootstrap: None,
         }
@@ -48,7 +48,7 @@ fn prepare_real_scenario(
     nodes_count_per_relay: u32,
     legacy_nodes_count_per_relay: u32,
     context: &Context<ChaChaRng>,
-) -> ControllerBuilder {
+) -> Result<ControllerBuilder> {
     let mut builder = ControllerBuilder::new(title);
     let mut topology_builder = TopologyBuilder::new();
 
@@ -116,8 +116,8 @@ fn prepare_real_scenario(
     }
 
     builder.set_blockchain(blockchain);
-    builder.build_settings(&mut context.clone());
-    builder
+    builder.build_settings(&mut context.clone())?;
+    Ok(builder)
 }
 
 pub fn real_network(context: Context<ChaChaRng>) -> Result<ScenarioResult> {
@@ -131,7 +131,7 @@ pub fn real_network(context: Context<ChaChaRng>) -> Result<ScenarioResult> {
         leaders_per_relay,
         legacies_per_relay,
         &context,
-    );
+    )?;
     let mut controller = scenario_settings.build(context)?;
 
     let core =
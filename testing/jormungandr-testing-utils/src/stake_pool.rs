@@ -2,10 +2,12 @@ use crate::wallet::Wallet;
 use chain_crypto::{Curve25519_2HashDH, Ed25519, SumEd25519_12};
 use chain_impl_mockchain::{
     certificate::{PoolId, PoolPermissions, PoolRegistration},
+    key::GenesisPraosLeader,
     rewards::{Ratio as RatioLib, TaxType},
     testing::{builders::StakePoolBuilder, data::StakePool as StakePoolLib},
     value::Value as ValueLib,
 };
+use chain_time::DurationSeconds;
 use jormungandr_lib::crypto::key::KeyPair;
 use std::num::NonZeroU64;
 
@@ -24,14 +26,7 @@ impl StakePool {
             .with_owners(vec![owner.identifier().into_public_key()])
             .with_pool_permissions(PoolPermissions::new(1))
             .with_reward_account(false)
-            .with_tax_type(TaxType {
-                fixed: ValueLib(100),
-                ratio: RatioLib {
-                    numerator: 1,
-                    denominator: NonZeroU64::new(10).unwrap(),
-                },
-                max_limit: None,
-            })
+            .with_tax_type(default_tax_type())
             .build();
 
         StakePool {
@@ -41,6 +36,36 @@ impl StakePool {
         }
     }
 
+    /// stake pool using the given KES and VRF keys, so the same owner and
+    /// keys always give the same pool id
+    pub fn from_keys(
+        owner: &Wallet,
+        kes: KeyPair<SumEd25519_12>,
+        vrf: KeyPair<Curve25519_2HashDH>,
+    ) -> Self {
+        let leader = KeyPair::<Ed25519>::generate(rand::rngs::OsRng);
+
+        let registration = PoolRegistration {
+            serial: 0,
+            owners: vec![owner.identifier().into_public_key()],
+            operators: Vec::new().into(),
+            permissions: PoolPermissions::new(1),
+            start_validity: DurationSeconds::from(0).into(),
+            rewards: default_tax_type(),
+            reward_account: None,
+            keys: GenesisPraosLeader {
+                kes_public_key: kes.identifier().into_public_key(),
+                vrf_public_key: vrf.identifier().into_public_key(),
+            },
+        };
+
+        StakePool {
+            owner: owner.clone(),
+            leader,
+            inner: StakePoolLib::new("", registration.to_id(), vrf.0, kes.0, registration, None),
+        }
+    }
+
     pub fn leader(&self) -> &KeyPair<Ed25519> {
         &self.leader
     }
@@ -70,6 +95,17 @@ impl StakePool {
     }
 }
 
+fn default_tax_type() -> TaxType {
+    TaxType {
+        fixed: ValueLib(100),
+        ratio: RatioLib {
+            numerator: 1,
+            denominator: NonZeroU64::new(10).unwrap(),
+        },
+        max_limit: None,
+    }
+}
+
 impl Into<StakePoolLib> for StakePool {
     fn into(self) -> StakePoolLib {
         self.inner
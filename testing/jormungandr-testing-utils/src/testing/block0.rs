@@ -0,0 +1,29 @@
+use chain_impl_mockchain::{
+    block::Block,
+    header::HeaderId,
+    ledger::{Error as LedgerError, Ledger},
+};
+use jormungandr_lib::interfaces::Block0Configuration;
+
+pub trait Block0ConfigurationExtension {
+    fn to_block0(&self) -> Block;
+    fn genesis_hash(&self) -> HeaderId;
+    /// ledger initialized from the block0 initials, as a node would do
+    /// at startup
+    fn to_ledger(&self) -> Result<Ledger, LedgerError>;
+}
+
+impl Block0ConfigurationExtension for Block0Configuration {
+    fn to_block0(&self) -> Block {
+        self.to_block()
+    }
+
+    fn genesis_hash(&self) -> HeaderId {
+        self.to_block0().header.hash()
+    }
+
+    fn to_ledger(&self) -> Result<Ledger, LedgerError> {
+        let block0 = self.to_block0();
+        Ledger::new(block0.header.hash(), block0.fragments())
+    }
+}
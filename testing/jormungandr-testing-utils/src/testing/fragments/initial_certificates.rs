@@ -1,5 +1,7 @@
+use super::FragmentBuilderError;
 use crate::{stake_pool::StakePool, wallet::Wallet};
 use chain_impl_mockchain::{
+    account::{DelegationRatio, DelegationType},
     certificate::{
         PoolId, PoolOwnersSigned, PoolSignature, SignedCertificate, StakeDelegation, VotePlan,
     },
@@ -7,9 +9,34 @@ use chain_impl_mockchain::{
 };
 
 pub fn signed_delegation_cert(wallet: &Wallet, pool_id: PoolId) -> SignedCertificate {
+    signed_stake_delegation_cert(wallet, DelegationType::Full(pool_id))
+}
+
+/// delegation of the stake of the wallet to several pools, each pool
+/// receiving its weight over the sum of all the weights
+///
+/// The weights must add up to at most 255, and the distribution must be
+/// a valid delegation ratio: not empty and without zero weights.
+pub fn signed_split_delegation_cert(
+    wallet: &Wallet,
+    distribution: Vec<(PoolId, u8)>,
+) -> Result<SignedCertificate, FragmentBuilderError> {
+    let weights: Vec<u8> = distribution.iter().map(|(_, weight)| *weight).collect();
+    let ratio = weights
+        .iter()
+        .try_fold(0u8, |parts, weight| parts.checked_add(*weight))
+        .and_then(|parts| DelegationRatio::new(parts, distribution))
+        .ok_or(FragmentBuilderError::InvalidDelegationRatio(weights))?;
+    Ok(signed_stake_delegation_cert(
+        wallet,
+        DelegationType::Ratio(ratio),
+    ))
+}
+
+fn signed_stake_delegation_cert(wallet: &Wallet, delegation: DelegationType) -> SignedCertificate {
     let stake_delegation = StakeDelegation {
         account_id: wallet.stake_key().unwrap(),
-        delegation: chain_impl_mockchain::account::DelegationType::Full(pool_id),
+        delegation,
     };
     let txb = TxBuilder::new()
         .set_payload(&stake_delegation)
@@ -40,3 +67,30 @@ pub fn signed_stake_pool_cert(stake_pool: &StakePool) -> SignedCertificate {
 pub fn vote_plan_cert(vote_plan: &VotePlan) -> SignedCertificate {
     SignedCertificate::VotePlan(vote_plan.clone(), ())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool_id() -> PoolId {
+        StakePool::new(&Wallet::new_account(&mut rand::rngs::OsRng)).id()
+    }
+
+    #[test]
+    fn split_delegation_rejects_invalid_distributions() {
+        let wallet = Wallet::new_account(&mut rand::rngs::OsRng);
+
+        assert!(
+            signed_split_delegation_cert(&wallet, vec![(pool_id(), 1), (pool_id(), 3)]).is_ok()
+        );
+
+        assert!(matches!(
+            signed_split_delegation_cert(&wallet, Vec::new()),
+            Err(FragmentBuilderError::InvalidDelegationRatio(weights)) if weights.is_empty()
+        ));
+        assert!(matches!(
+            signed_split_delegation_cert(&wallet, vec![(pool_id(), 200), (pool_id(), 100)]),
+            Err(FragmentBuilderError::InvalidDelegationRatio(weights)) if weights == vec![200, 100]
+        ));
+    }
+}
@@ -1,13 +1,22 @@
 pub use self::{
     export::{FragmentExporter, FragmentExporterError},
-    initial_certificates::{signed_delegation_cert, signed_stake_pool_cert, vote_plan_cert},
+    initial_certificates::{
+        signed_delegation_cert, signed_split_delegation_cert, signed_stake_pool_cert,
+        vote_plan_cert,
+    },
     node::{FragmentNode, FragmentNodeError, MemPoolCheck},
     sender::{FragmentSender, FragmentSenderError},
     setup::{FragmentSenderSetup, FragmentSenderSetupBuilder, VerifyStrategy},
-    transaction::transaction_to,
+    transaction::{
+        double_spend_pair, plan_transaction, transaction_to, transaction_to_signed_with,
+        TransactionPlan,
+    },
     verifier::{FragmentVerifier, FragmentVerifierError},
 };
-use crate::{stake_pool::StakePool, wallet::Wallet};
+use crate::{
+    stake_pool::StakePool,
+    wallet::{SigningAlgorithm, Wallet},
+};
 use chain_impl_mockchain::{
     certificate::{PoolId, VotePlan},
     fee::LinearFee,
@@ -16,6 +25,7 @@ use chain_impl_mockchain::{
         data::{StakePool as StakePoolLib, Wallet as WalletLib},
         scenario::FragmentFactory,
     },
+    transaction::UtxoPointer,
 };
 use jormungandr_lib::{
     crypto::hash::Hash,
@@ -41,6 +51,18 @@ pub enum FragmentBuilderError {
     TransactionAlreadyBalanced,
     #[error("the transaction has {0} value extra than necessary")]
     TransactionAlreadyExtraValue(Value),
+    #[error("cannot witness a transaction input with {0:?}")]
+    IncompatibleSigningAlgorithm(SigningAlgorithm),
+    #[error("utxo {0:?} is not in the ledger")]
+    UnknownUtxo(UtxoPointer),
+    #[error("inputs of {available} cannot cover the {needed} needed by the outputs and fee")]
+    NotEnoughFunds { needed: Value, available: Value },
+    #[error("a transaction cannot have {inputs} inputs and {outputs} outputs")]
+    TooManyInputsOrOutputs { inputs: usize, outputs: usize },
+    #[error("the sum of the transaction's values overflows")]
+    ValueOverflow,
+    #[error("delegation weights {0:?} do not form a valid delegation ratio")]
+    InvalidDelegationRatio(Vec<u8>),
 }
 
 pub struct FragmentBuilder {
@@ -69,10 +91,36 @@ impl FragmentBuilder {
         transaction_to(&self.block0_hash, &self.fees, from, address, value)
     }
 
+    pub fn transaction_signed_with(
+        &self,
+        from: &Wallet,
+        address: Address,
+        value: Value,
+        algorithm: SigningAlgorithm,
+    ) -> Result<Fragment, FragmentBuilderError> {
+        transaction_to_signed_with(
+            &self.block0_hash,
+            &self.fees,
+            from,
+            address,
+            value,
+            algorithm,
+        )
+    }
+
     pub fn full_delegation_cert_for_block0(wallet: &Wallet, pool_id: PoolId) -> Initial {
         Initial::Cert(signed_delegation_cert(wallet, pool_id).into())
     }
 
+    pub fn split_delegation_cert_for_block0(
+        wallet: &Wallet,
+        distribution: Vec<(PoolId, u8)>,
+    ) -> Result<Initial, FragmentBuilderError> {
+        Ok(Initial::Cert(
+            signed_split_delegation_cert(wallet, distribution)?.into(),
+        ))
+    }
+
     pub fn stake_pool_registration(&self, funder: &Wallet, stake_pool: &StakePool) -> Fragment {
         let inner_wallet = funder.clone().into();
         self.fragment_factory()
@@ -1,14 +1,17 @@
 use super::FragmentBuilderError;
-use crate::wallet::Wallet;
+use crate::wallet::{ChangeStrategy, SigningAlgorithm, Wallet, WalletError};
 use chain_impl_mockchain::{
-    fee::LinearFee,
+    fee::{FeeAlgorithm, LinearFee},
     fragment::Fragment,
-    transaction::{InputOutputBuilder, NoExtra, Payload, TxBuilder},
+    ledger::Ledger,
+    transaction::{InputOutputBuilder, NoExtra, Payload, TxBuilder, UtxoPointer},
+    value::Value as ChainValue,
 };
 use jormungandr_lib::{
     crypto::hash::Hash,
-    interfaces::{Address, Value},
+    interfaces::{Address, UTxOInfo, Value},
 };
+use std::convert::TryFrom;
 
 pub fn transaction_to(
     block0_hash: &Hash,
@@ -16,6 +19,24 @@ pub fn transaction_to(
     from: &Wallet,
     address: Address,
     value: Value,
+) -> Result<Fragment, FragmentBuilderError> {
+    transaction_to_signed_with(
+        block0_hash,
+        fees,
+        from,
+        address,
+        value,
+        from.signing_algorithm(),
+    )
+}
+
+pub fn transaction_to_signed_with(
+    block0_hash: &Hash,
+    fees: &LinearFee,
+    from: &Wallet,
+    address: Address,
+    value: Value,
+    algorithm: SigningAlgorithm,
 ) -> Result<Fragment, FragmentBuilderError> {
     let mut iobuilder = InputOutputBuilder::empty();
     iobuilder.add_output(address.into(), value.into()).unwrap();
@@ -29,8 +50,213 @@ pub fn transaction_to(
         .set_ios(&ios.inputs, &ios.outputs);
 
     let sign_data = txbuilder.get_auth_data_for_witness().hash();
-    let witness = from.mk_witness(block0_hash, &sign_data);
+    let witness = from.mk_witness_with(algorithm, block0_hash, &sign_data)?;
     let witnesses = vec![witness];
     let tx = txbuilder.set_witnesses(&witnesses).set_payload_auth(&());
     Ok(Fragment::Transaction(tx))
 }
+
+/// values of a transaction spending some utxos to a set of recipients
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionPlan {
+    /// total value of the spent utxos
+    pub input: Value,
+    /// total value sent to the recipients
+    pub output: Value,
+    pub fee: Value,
+    /// value sent back to the sender in an extra output, zero when there
+    /// is no change output
+    pub change: Value,
+}
+
+/// plans a transaction spending the `from` utxos, as found in `ledger`, to
+/// the `recipients`.
+///
+/// A change output is added when what is left after the recipients and the
+/// fee (including the change output's own cost) is positive. Otherwise the
+/// leftover, if any, is left to the fee.
+pub fn plan_transaction(
+    ledger: &Ledger,
+    from: &[UtxoPointer],
+    recipients: &[(Address, Value)],
+    fees: &LinearFee,
+) -> Result<TransactionPlan, FragmentBuilderError> {
+    let too_many = || FragmentBuilderError::TooManyInputsOrOutputs {
+        inputs: from.len(),
+        outputs: recipients.len(),
+    };
+    let inputs_count = u8::try_from(from.len()).map_err(|_| too_many())?;
+    let outputs_count = u8::try_from(recipients.len()).map_err(|_| too_many())?;
+
+    let mut input = ChainValue::zero();
+    for pointer in from {
+        let utxo = ledger
+            .utxo_out(pointer.transaction_id, pointer.output_index)
+            .ok_or(FragmentBuilderError::UnknownUtxo(*pointer))?;
+        input = input
+            .checked_add(utxo.value)
+            .map_err(|_| FragmentBuilderError::ValueOverflow)?;
+    }
+    let output = ChainValue::sum(recipients.iter().map(|(_, value)| (*value).into()))
+        .map_err(|_| FragmentBuilderError::ValueOverflow)?;
+
+    let fee = fees.calculate(None, inputs_count, outputs_count);
+    let needed = output
+        .checked_add(fee)
+        .map_err(|_| FragmentBuilderError::ValueOverflow)?;
+    if input < needed {
+        return Err(FragmentBuilderError::NotEnoughFunds {
+            needed: needed.into(),
+            available: input.into(),
+        });
+    }
+
+    // a change output is only possible while there is room for one more
+    // output, and only worth it when it is left with a positive value
+    let with_change = outputs_count.checked_add(1).and_then(|outputs_count| {
+        let fee = fees.calculate(None, inputs_count, outputs_count);
+        let needed = output.checked_add(fee).ok()?;
+        let change = input.checked_sub(needed).ok()?;
+        if change > ChainValue::zero() {
+            Some((fee, change))
+        } else {
+            None
+        }
+    });
+    let (fee, change) = match with_change {
+        Some(with_change) => with_change,
+        None => (
+            input
+                .checked_sub(output)
+                .map_err(|_| FragmentBuilderError::ValueOverflow)?,
+            ChainValue::zero(),
+        ),
+    };
+
+    Ok(TransactionPlan {
+        input: input.into(),
+        output: output.into(),
+        fee: fee.into(),
+        change: change.into(),
+    })
+}
+
+/// builds two transactions consuming the same `utxo` of `wallet`, the first
+/// one paying `out_a` and the second one paying `out_b`.
+///
+/// Both are valid on their own. Once one of them is applied to the ledger
+/// the other one is rejected with an invalid utxo error, as its input no
+/// longer exists.
+pub fn double_spend_pair(
+    wallet: &mut Wallet,
+    block0_hash: &Hash,
+    utxo: &UTxOInfo,
+    out_a: (Address, Value),
+    out_b: (Address, Value),
+    fees: &LinearFee,
+) -> Result<(Fragment, Fragment), WalletError> {
+    let (address_a, value_a) = out_a;
+    let (address_b, value_b) = out_b;
+    let first = wallet.utxo_transaction(
+        block0_hash,
+        fees,
+        utxo,
+        address_a,
+        value_a,
+        ChangeStrategy::SameAddress,
+    )?;
+    let second = wallet.utxo_transaction(
+        block0_hash,
+        fees,
+        utxo,
+        address_b,
+        value_b,
+        ChangeStrategy::SameAddress,
+    )?;
+    Ok((first, second))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Block0ConfigurationExtension;
+    use chain_addr::Discrimination;
+    use chain_core::property::Fragment as _;
+    use chain_crypto::{Ed25519, SecretKey};
+    use chain_impl_mockchain::{chaintypes::ConsensusVersion, key::BftLeaderId};
+    use jormungandr_lib::interfaces::{
+        Block0Configuration, BlockchainConfiguration, ConsensusLeaderId, Initial, InitialUTxO,
+    };
+
+    #[test]
+    fn plan_transaction_computes_fee_and_change() {
+        let sender = Wallet::new_utxo(&mut rand::rngs::OsRng);
+        let leader = SecretKey::<Ed25519>::generate(rand::rngs::OsRng).to_public();
+        let mut blockchain_configuration = BlockchainConfiguration::new(
+            Discrimination::Test,
+            ConsensusVersion::Bft,
+            LinearFee::new(0, 0, 0),
+        );
+        blockchain_configuration.consensus_leader_ids =
+            vec![ConsensusLeaderId(BftLeaderId::from(leader))];
+        let funds = Initial::Fund(vec![InitialUTxO {
+            address: sender.address(),
+            value: 1_000.into(),
+        }]);
+        let block0 = Block0Configuration {
+            blockchain_configuration,
+            initial: vec![funds.clone()],
+        };
+        let ledger = block0.to_ledger().unwrap();
+        let utxo = UtxoPointer {
+            transaction_id: Fragment::from(&funds).id(),
+            output_index: 0,
+            value: ChainValue(1_000),
+        };
+
+        let recipients = vec![
+            (
+                Wallet::new_account(&mut rand::rngs::OsRng).address(),
+                500.into(),
+            ),
+            (
+                Wallet::new_account(&mut rand::rngs::OsRng).address(),
+                300.into(),
+            ),
+        ];
+        // 1 input and 3 outputs, the last one being the change
+        let fees = LinearFee::new(10, 2, 0);
+        let plan = plan_transaction(&ledger, &[utxo], &recipients, &fees).unwrap();
+
+        assert_eq!(
+            plan,
+            TransactionPlan {
+                input: 1_000.into(),
+                output: 800.into(),
+                fee: 18.into(),
+                change: 182.into(),
+            }
+        );
+
+        let fees = LinearFee::new(250, 0, 0);
+        assert!(matches!(
+            plan_transaction(&ledger, &[utxo], &recipients, &fees),
+            Err(FragmentBuilderError::NotEnoughFunds { .. })
+        ));
+
+        let too_many_recipients = vec![recipients[0].clone(); 256];
+        assert!(matches!(
+            plan_transaction(&ledger, &[utxo], &too_many_recipients, &fees),
+            Err(FragmentBuilderError::TooManyInputsOrOutputs {
+                inputs: 1,
+                outputs: 256
+            })
+        ));
+
+        let overflowing_recipients = vec![(recipients[0].0.clone(), u64::MAX.into()); 2];
+        assert!(matches!(
+            plan_transaction(&ledger, &[utxo], &overflowing_recipients, &fees),
+            Err(FragmentBuilderError::ValueOverflow)
+        ));
+    }
+}
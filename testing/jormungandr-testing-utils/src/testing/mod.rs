@@ -1,4 +1,5 @@
 mod archive;
+mod block0;
 mod fragments;
 pub mod github;
 /// Module contains cross project test utils
@@ -9,11 +10,13 @@ mod verify;
 mod web;
 
 pub use archive::decompress;
+pub use block0::Block0ConfigurationExtension;
 pub use fragments::{
-    signed_delegation_cert, signed_stake_pool_cert, vote_plan_cert, FragmentBuilder,
-    FragmentBuilderError, FragmentNode, FragmentNodeError, FragmentSender, FragmentSenderError,
-    FragmentSenderSetup, FragmentSenderSetupBuilder, FragmentVerifier, FragmentVerifierError,
-    MemPoolCheck, VerifyStrategy,
+    double_spend_pair, plan_transaction, signed_delegation_cert, signed_split_delegation_cert,
+    signed_stake_pool_cert, vote_plan_cert, FragmentBuilder, FragmentBuilderError, FragmentNode,
+    FragmentNodeError, FragmentSender, FragmentSenderError, FragmentSenderSetup,
+    FragmentSenderSetupBuilder, FragmentVerifier, FragmentVerifierError, MemPoolCheck,
+    TransactionPlan, VerifyStrategy,
 };
 pub use measurement::{
     benchmark_consumption, benchmark_efficiency, benchmark_endurance, benchmark_speed,
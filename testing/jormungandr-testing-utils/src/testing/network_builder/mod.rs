@@ -8,10 +8,10 @@ mod wallet;
 pub use blockchain::Blockchain;
 use chain_impl_mockchain::header::HeaderId;
 pub use rng::{Random, Seed};
-pub use settings::{NodeSetting, Settings};
+pub use settings::{NodeSetting, Settings, SettingsError};
 pub use spawn_params::SpawnParams;
 use std::path::PathBuf;
-pub use topology::{Node, NodeAlias, Topology, TopologyBuilder};
+pub use topology::{random_topology, Node, NodeAlias, Topology, TopologyBuilder};
 pub use wallet::{Wallet, WalletAlias, WalletTemplate, WalletType};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -1,19 +1,53 @@
 use crate::testing::network_builder::{
-    Blockchain as BlockchainTemplate, Node as NodeTemplate, NodeAlias, Random, Wallet, WalletAlias,
-    WalletTemplate, WalletType,
+    Blockchain as BlockchainTemplate, LeadershipMode, Node as NodeTemplate, NodeAlias, Random,
+    Wallet, WalletAlias, WalletTemplate, WalletType,
 };
-use crate::{stake_pool::StakePool, testing::signed_stake_pool_cert, wallet::Wallet as WalletLib};
+use crate::{
+    stake_pool::StakePool,
+    testing::{signed_stake_pool_cert, FragmentBuilderError},
+    wallet::Wallet as WalletLib,
+};
+use chain_core::property::Fragment as _;
 use chain_crypto::Ed25519;
-use chain_impl_mockchain::{chaintypes::ConsensusVersion, fee::LinearFee};
+use chain_impl_mockchain::{
+    certificate::PoolId, chaintypes::ConsensusVersion, fee::LinearFee, fragment::Fragment,
+    transaction::UtxoPointer,
+};
 use jormungandr_lib::{
-    crypto::key::SigningKey,
+    crypto::key::{Signature, SigningKey},
     interfaces::{
-        ActiveSlotCoefficient, Bft, Block0Configuration, BlockchainConfiguration, GenesisPraos,
-        Initial, InitialUTxO, NodeConfig, NodeSecret,
+        ActiveSlotCoefficient, Address, Bft, Block0Configuration, BlockchainConfiguration,
+        GenesisPraos, Initial, InitialUTxO, NodeConfig, NodeSecret,
     },
 };
 use rand_core::{CryptoRng, RngCore};
 use std::collections::HashMap;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SettingsError {
+    #[error("genesis praos network requires at least one stake pool in block0, delegate a wallet to one of the nodes")]
+    NoStakePool,
+    #[error("passive node '{0}' cannot be a BFT leader")]
+    PassiveLeader(NodeAlias),
+    #[error("wallets cannot delegate to passive node '{0}'")]
+    DelegationToPassiveNode(NodeAlias),
+    #[error("wallet '{0}' cannot delegate both to a single node and by ratio")]
+    DelegationToNodeAndRatio(WalletAlias),
+    #[error("wallet '{wallet}' delegation by ratio is invalid")]
+    InvalidDelegationRatio {
+        wallet: WalletAlias,
+        #[source]
+        source: FragmentBuilderError,
+    },
+    #[error("wallets {0:?} delegate in block0 but are not funded by any initial")]
+    OrphanedDelegations(Vec<WalletAlias>),
+    #[error("wallet '{wallet}' delegates to nodes that are not in the topology: {nodes:?}")]
+    DelegationToUnknownNodes {
+        wallet: WalletAlias,
+        nodes: Vec<NodeAlias>,
+    },
+}
 
 /// contains all the data to start or interact with a node
 #[derive(Debug, Clone)]
@@ -27,6 +61,10 @@ pub struct NodeSetting {
     /// stake pool)
     pub secret: NodeSecret,
 
+    /// secrets of the stake pools operated by the node on top of
+    /// the one in `secret`, each one passed as a separate secret file
+    pub additional_secrets: Vec<NodeSecret>,
+
     pub config: NodeConfig,
 
     pub node_topology: NodeTemplate,
@@ -43,6 +81,7 @@ impl NodeSetting {
             alias,
             config,
             secret,
+            additional_secrets: Vec::new(),
             node_topology: template,
         }
     }
@@ -54,6 +93,30 @@ impl NodeSetting {
     pub fn secrets(&self) -> &NodeSecret {
         &self.secret
     }
+
+    pub fn additional_secrets(&self) -> &[NodeSecret] {
+        &self.additional_secrets
+    }
+
+    /// sign the given payload with the BFT signing key of the node,
+    /// `None` if the node is not a BFT leader
+    pub fn sign_bft<T: AsRef<[u8]>>(&self, payload: &T) -> Option<Signature<T, Ed25519>> {
+        self.secret
+            .bft
+            .as_ref()
+            .map(|bft| bft.signing_key.sign(payload))
+    }
+}
+
+fn genesis_praos_secret(stake_pool: &StakePool) -> GenesisPraos {
+    GenesisPraos {
+        sig_key: stake_pool.kes().signing_key(),
+        vrf_key: stake_pool.vrf().signing_key(),
+        node_id: {
+            let bytes: [u8; 32] = stake_pool.id().into();
+            bytes.into()
+        },
+    }
 }
 
 #[derive(Debug)]
@@ -70,7 +133,7 @@ impl Settings {
         nodes: HashMap<NodeAlias, NodeSetting>,
         blockchain: BlockchainTemplate,
         rng: &mut Random<RNG>,
-    ) -> Self
+    ) -> Result<Self, SettingsError>
     where
         RNG: RngCore + CryptoRng,
     {
@@ -88,17 +151,110 @@ impl Settings {
         };
 
         settings.populate_trusted_peers();
-        settings.populate_block0_blockchain_configuration(&blockchain, rng);
-        settings.populate_block0_blockchain_initials(blockchain.wallets(), rng);
+        settings.populate_block0_blockchain_configuration(&blockchain, rng)?;
+        settings.populate_block0_blockchain_initials(blockchain.wallets(), rng)?;
+
+        if *blockchain.consensus() == ConsensusVersion::GenesisPraos
+            && !settings.has_stake_pool_registration()
+        {
+            return Err(SettingsError::NoStakePool);
+        }
+
+        let orphans = settings.orphaned_delegations();
+        if !orphans.is_empty() {
+            return Err(SettingsError::OrphanedDelegations(orphans));
+        }
+
+        Ok(settings)
+    }
+
+    /// outpoint of the block0 UTxO funding the given wallet, `None` if the
+    /// wallet does not exist or is an account wallet
+    pub fn wallet_utxo(&self, alias: &str) -> Option<UtxoPointer> {
+        let wallet = self.wallets.get(alias)?;
+        if *wallet.template().wallet_type() != WalletType::UTxO {
+            return None;
+        }
+
+        let address = wallet.address();
+        self.block0
+            .initial
+            .iter()
+            .find_map(|initial| match initial {
+                Initial::Fund(utxos) => {
+                    utxos
+                        .iter()
+                        .position(|utxo| utxo.address == address)
+                        .map(|index| UtxoPointer {
+                            transaction_id: Fragment::from(initial).id(),
+                            output_index: index as u8,
+                            value: utxos[index].value.into(),
+                        })
+                }
+                _ => None,
+            })
+    }
+
+    /// aliases of the wallets delegating in block0 while no block0 initial
+    /// funds them, their delegation certificates are only dead weight
+    pub fn orphaned_delegations(&self) -> Vec<WalletAlias> {
+        use chain_impl_mockchain::certificate::SignedCertificate;
+
+        let delegated: Vec<_> = self
+            .block0
+            .initial
+            .iter()
+            .filter_map(|initial| match initial {
+                Initial::Cert(cert) => match &cert.0 {
+                    SignedCertificate::StakeDelegation(delegation, _) => {
+                        Some(delegation.account_id.clone())
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+
+        let mut orphans: Vec<WalletAlias> = self
+            .wallets
+            .iter()
+            .filter(|(_, wallet)| *wallet.template().wallet_type() == WalletType::Account)
+            .filter(|(_, wallet)| {
+                wallet
+                    .stake_key()
+                    .map_or(false, |stake_key| delegated.contains(&stake_key))
+            })
+            .filter(|(_, wallet)| !self.is_funded(&wallet.address()))
+            .map(|(alias, _)| alias.clone())
+            .collect();
+        orphans.sort();
+        orphans
+    }
 
-        settings
+    fn is_funded(&self, address: &Address) -> bool {
+        self.block0.initial.iter().any(|initial| match initial {
+            Initial::Fund(utxos) => utxos
+                .iter()
+                .any(|utxo| utxo.address == *address && utxo.value != 0.into()),
+            _ => false,
+        })
+    }
+
+    fn has_stake_pool_registration(&self) -> bool {
+        use chain_impl_mockchain::certificate::SignedCertificate;
+
+        self.block0.initial.iter().any(|initial| match initial {
+            Initial::Cert(cert) => matches!(cert.0, SignedCertificate::PoolRegistration(..)),
+            _ => false,
+        })
     }
 
     fn populate_block0_blockchain_configuration<RNG>(
         &mut self,
         blockchain: &BlockchainTemplate,
         rng: &mut Random<RNG>,
-    ) where
+    ) -> Result<(), SettingsError>
+    where
         RNG: RngCore + CryptoRng,
     {
         let mut blockchain_configuration = &mut self.block0.blockchain_configuration;
@@ -110,6 +266,9 @@ impl Settings {
             let mut leader_ids = Vec::new();
             for leader_alias in blockchain.leaders() {
                 let identifier = if let Some(node) = self.nodes.get_mut(leader_alias) {
+                    if node.node_topology.leadership_mode() != LeadershipMode::Leader {
+                        return Err(SettingsError::PassiveLeader(leader_alias.clone()));
+                    }
                     if let Some(bft) = &node.secret.bft {
                         bft.signing_key.identifier()
                     } else {
@@ -123,6 +282,12 @@ impl Settings {
                 };
                 leader_ids.push(identifier.into());
             }
+            // the ledger refuses a block0 without leader ids, even when the
+            // blocks are produced by the stake pools of a genesis praos network
+            if leader_ids.is_empty() {
+                let identifier = SigningKey::<Ed25519>::generate(rng.rng_mut()).identifier();
+                leader_ids.push(identifier.into());
+            }
             leader_ids
         };
         blockchain_configuration.slots_per_epoch = *blockchain.slots_per_epoch();
@@ -131,16 +296,23 @@ impl Settings {
         blockchain_configuration.kes_update_speed = *blockchain.kes_update_speed();
         blockchain_configuration.consensus_genesis_praos_active_slot_coeff =
             ActiveSlotCoefficient::MAXIMUM;
+        Ok(())
     }
 
     fn populate_block0_blockchain_initials<'a, RNG, I>(
         &'a mut self,
         wallet_templates: I,
         rng: &mut Random<RNG>,
-    ) where
+    ) -> Result<(), SettingsError>
+    where
         RNG: RngCore + CryptoRng,
         I: Iterator<Item = &'a WalletTemplate>,
     {
+        // generate the wallets in a stable order so a given seed always
+        // produces the same keys, hence the same block0 outpoints
+        let mut wallet_templates: Vec<&WalletTemplate> = wallet_templates.collect();
+        wallet_templates.sort_by(|left, right| left.alias().cmp(right.alias()));
+
         for wallet_template in wallet_templates {
             // TODO: check the wallet does not already exist ?
             let wallet = match wallet_template.wallet_type() {
@@ -162,48 +334,100 @@ impl Settings {
                 .insert(wallet_template.alias().clone(), wallet.clone());
             self.block0.initial.push(initial_fragment);
 
+            let ratios = wallet_template.delegate_ratios();
             if let Some(delegation) = wallet_template.delegate() {
-                use chain_impl_mockchain::certificate::PoolId as StakePoolId;
+                if !ratios.is_empty() {
+                    return Err(SettingsError::DelegationToNodeAndRatio(
+                        wallet_template.alias().clone(),
+                    ));
+                }
 
                 // 1. retrieve the public data (we may need to create a stake pool
                 //    registration here)
-                let stake_pool_id: StakePoolId = if let Some(node) = self.nodes.get_mut(delegation)
-                {
-                    if let Some(genesis) = &node.secret.genesis {
-                        genesis.node_id.clone().into_digest_of()
-                    } else {
-                        // create and register the stake pool
-                        let owner = WalletLib::new_account(&mut rand::rngs::OsRng);
-                        let stake_pool = StakePool::new(&owner);
-                        let node_id = stake_pool.id();
-                        node.secret.genesis = Some(GenesisPraos {
-                            sig_key: stake_pool.kes().signing_key(),
-                            vrf_key: stake_pool.vrf().signing_key(),
-                            node_id: {
-                                let bytes: [u8; 32] = node_id.clone().into();
-                                bytes.into()
-                            },
-                        });
-
-                        self.block0
-                            .initial
-                            .push(Initial::Cert(signed_stake_pool_cert(&stake_pool).into()));
-
-                        node_id
-                    }
-                } else {
-                    // delegating to a node that does not exist in the topology
-                    // so generate valid stake pool registration and delegation
-                    // to that node.
-                    unimplemented!("delegating stake to a stake pool that is not a node is not supported (yet)")
-                };
+                let stake_pool_id = self.node_stake_pool_id(delegation)?;
 
                 // 2. create delegation certificate for the wallet stake key
                 // and add it to the block0.initial array
                 let delegation_certificate = wallet.delegation_cert_for_block0(stake_pool_id);
 
                 self.block0.initial.push(delegation_certificate);
+            } else if !ratios.is_empty() {
+                // only delegate once all the nodes are known to be part of the
+                // topology, rather than registering the stake pools of some of
+                // them and failing on the next one
+                let unknown: Vec<NodeAlias> = ratios
+                    .iter()
+                    .map(|(alias, _)| alias)
+                    .filter(|alias| !self.nodes.contains_key(*alias))
+                    .cloned()
+                    .collect();
+                if !unknown.is_empty() {
+                    return Err(SettingsError::DelegationToUnknownNodes {
+                        wallet: wallet_template.alias().clone(),
+                        nodes: unknown,
+                    });
+                }
+
+                let distribution = ratios
+                    .iter()
+                    .map(|(alias, weight)| Ok((self.node_stake_pool_id(alias)?, *weight)))
+                    .collect::<Result<_, SettingsError>>()?;
+
+                let delegation_certificate = wallet
+                    .split_delegation_cert_for_block0(distribution)
+                    .map_err(|source| SettingsError::InvalidDelegationRatio {
+                        wallet: wallet_template.alias().clone(),
+                        source,
+                    })?;
+                self.block0.initial.push(delegation_certificate);
+            }
+        }
+        Ok(())
+    }
+
+    /// id of the stake pool operated by the given node, registering the
+    /// stake pools of the node in block0 the first time it is delegated to
+    fn node_stake_pool_id(&mut self, alias: &NodeAlias) -> Result<PoolId, SettingsError> {
+        if let Some(node) = self.nodes.get_mut(alias) {
+            if node.node_topology.leadership_mode() != LeadershipMode::Leader {
+                return Err(SettingsError::DelegationToPassiveNode(alias.clone()));
+            }
+            if let Some(genesis) = &node.secret.genesis {
+                Ok(genesis.node_id.clone().into_digest_of())
+            } else {
+                // create and register the stake pools, the wallet
+                // delegates to the first one
+                let count = node.node_topology.stake_pools().max(1);
+                let stake_pools: Vec<StakePool> = (0..count)
+                    .map(|_| {
+                        let owner = WalletLib::new_account(&mut rand::rngs::OsRng);
+                        StakePool::new(&owner)
+                    })
+                    .collect();
+
+                node.secret.genesis = Some(genesis_praos_secret(&stake_pools[0]));
+                for stake_pool in &stake_pools[1..] {
+                    node.additional_secrets.push(NodeSecret {
+                        bft: None,
+                        genesis: Some(genesis_praos_secret(stake_pool)),
+                    });
+                }
+
+                for stake_pool in &stake_pools {
+                    self.block0
+                        .initial
+                        .push(Initial::Cert(signed_stake_pool_cert(stake_pool).into()));
+                }
+
+                Ok(stake_pools[0].id())
             }
+        } else {
+            // delegating to a node that does not exist in the topology
+            // so generate valid stake pool registration and delegation
+            // to that node.
+            unimplemented!(
+                "delegating stake to a stake pool that is not a node is not supported (yet)"
+            )
         }
     }
 
@@ -1,3 +1,5 @@
+use super::LeadershipMode;
+use rand::{seq::SliceRandom, Rng};
 use std::{borrow::Borrow, collections::HashMap, hash::Hash};
 
 pub type NodeAlias = String;
@@ -7,6 +9,13 @@ pub struct Node {
     alias: NodeAlias,
 
     trusted_peers: Vec<NodeAlias>,
+
+    /// number of stake pools registered in block0 for this node
+    /// when wallets delegate to it
+    stake_pools: usize,
+
+    /// passive nodes never receive any leader secret
+    leadership_mode: LeadershipMode,
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +33,19 @@ impl Node {
         Node {
             alias: alias.into(),
             trusted_peers: Vec::new(),
+            stake_pools: 1,
+            leadership_mode: LeadershipMode::Leader,
+        }
+    }
+
+    /// relay node without any secret material, bootstrapping from and
+    /// connected to the given trusted peers
+    pub fn passive<S: Into<NodeAlias>>(alias: S, trusted_peers: Vec<NodeAlias>) -> Self {
+        Node {
+            alias: alias.into(),
+            trusted_peers,
+            stake_pools: 0,
+            leadership_mode: LeadershipMode::Passive,
         }
     }
 
@@ -38,6 +60,18 @@ impl Node {
     pub fn trusted_peers(&self) -> impl Iterator<Item = &NodeAlias> {
         self.trusted_peers.iter()
     }
+
+    pub fn set_stake_pools(&mut self, count: usize) {
+        self.stake_pools = count
+    }
+
+    pub fn stake_pools(&self) -> usize {
+        self.stake_pools
+    }
+
+    pub fn leadership_mode(&self) -> LeadershipMode {
+        self.leadership_mode
+    }
 }
 
 impl Topology {
@@ -101,3 +135,102 @@ impl Default for TopologyBuilder {
         Self::new()
     }
 }
+
+/// generate a connected topology of `node_count` nodes named `node_<index>`.
+///
+/// Every node but the first one trusts a random node created before it so
+/// all the nodes are connected, then trusted peers are picked at random until
+/// every node has `degree` of them (or trusts all the other nodes). The first
+/// `leader_fraction` of the nodes, rounded and at least one, are leaders, the
+/// others are passive nodes.
+pub fn random_topology<RNG: Rng>(
+    rng: &mut RNG,
+    node_count: usize,
+    degree: usize,
+    leader_fraction: f64,
+) -> Topology {
+    assert!(node_count > 0, "a topology needs at least one node");
+    assert!(
+        (0.0..=1.0).contains(&leader_fraction),
+        "leader fraction must be within [0, 1]"
+    );
+
+    let aliases: Vec<NodeAlias> = (0..node_count)
+        .map(|index| format!("node_{}", index))
+        .collect();
+    let leader_count = ((node_count as f64 * leader_fraction).round() as usize).max(1);
+
+    let mut peers: Vec<Vec<NodeAlias>> = vec![Vec::new(); node_count];
+    for (index, node_peers) in peers.iter_mut().enumerate().skip(1) {
+        node_peers.push(aliases[rng.gen_range(0, index)].clone());
+    }
+    for (index, node_peers) in peers.iter_mut().enumerate() {
+        let mut candidates: Vec<&NodeAlias> = aliases
+            .iter()
+            .enumerate()
+            .filter(|(other, alias)| *other != index && !node_peers.contains(*alias))
+            .map(|(_, alias)| alias)
+            .collect();
+        candidates.shuffle(rng);
+        let missing = degree.saturating_sub(node_peers.len());
+        node_peers.extend(candidates.into_iter().take(missing).cloned());
+    }
+
+    let mut builder = TopologyBuilder::new();
+    for (index, (alias, trusted_peers)) in aliases.into_iter().zip(peers).enumerate() {
+        let node = if index < leader_count {
+            let mut node = Node::new(alias);
+            for trusted_peer in trusted_peers {
+                node.add_trusted_peer(trusted_peer);
+            }
+            node
+        } else {
+            Node::passive(alias, trusted_peers)
+        };
+        builder.register_node(node);
+    }
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_chacha::ChaChaRng;
+    use rand_core::SeedableRng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn random_topology_is_connected_and_valid() {
+        let mut rng = ChaChaRng::from_seed([3; 32]);
+        let topology = random_topology(&mut rng, 10, 3, 0.3);
+
+        assert_eq!(topology.aliases().count(), 10);
+        let leaders = topology
+            .aliases()
+            .filter_map(|alias| topology.node(alias))
+            .filter(|node| node.leadership_mode() == LeadershipMode::Leader)
+            .count();
+        assert_eq!(leaders, 3);
+
+        let mut edges: HashMap<&NodeAlias, Vec<&NodeAlias>> = HashMap::new();
+        for alias in topology.aliases() {
+            let node = topology.node(alias).unwrap();
+            assert!(node.trusted_peers().count() >= 3);
+            for peer in node.trusted_peers() {
+                assert_ne!(peer, alias, "node '{}' trusts itself", alias);
+                assert!(topology.node(peer).is_some());
+                edges.entry(alias).or_default().push(peer);
+                edges.entry(peer).or_default().push(alias);
+            }
+        }
+
+        let mut reached = HashSet::new();
+        let mut to_visit = vec![topology.aliases().next().unwrap()];
+        while let Some(alias) = to_visit.pop() {
+            if reached.insert(alias) {
+                to_visit.extend(edges[alias].iter().cloned());
+            }
+        }
+        assert_eq!(reached.len(), 10, "topology is not connected");
+    }
+}
@@ -1,7 +1,10 @@
 use super::NodeAlias;
+use crate::testing::FragmentBuilderError;
 use crate::wallet::{
     account::Wallet as AccountWallet, utxo::Wallet as UtxOWallet, Wallet as Inner, WalletError,
 };
+use chain_addr::Discrimination;
+use chain_crypto::{Ed25519, SecretKey};
 use chain_impl_mockchain::{
     certificate::PoolId, fee::LinearFee, fragment::Fragment,
     transaction::UnspecifiedAccountIdentifier,
@@ -27,6 +30,9 @@ pub struct WalletTemplate {
     value: Value,
     wallet_type: WalletType,
     delegate: Option<NodeAlias>,
+    /// nodes receiving a share of the stake, weighted, instead of a
+    /// single `delegate`
+    delegate_ratios: Vec<(NodeAlias, u8)>,
 }
 
 impl WalletTemplate {
@@ -44,6 +50,7 @@ impl WalletTemplate {
             value,
             wallet_type,
             delegate: None,
+            delegate_ratios: Vec::new(),
         }
     }
 
@@ -66,6 +73,14 @@ impl WalletTemplate {
     pub fn delegate_mut(&mut self) -> &mut Option<NodeAlias> {
         &mut self.delegate
     }
+
+    pub fn delegate_ratios(&self) -> &[(NodeAlias, u8)] {
+        &self.delegate_ratios
+    }
+
+    pub fn delegate_ratios_mut(&mut self) -> &mut Vec<(NodeAlias, u8)> {
+        &mut self.delegate_ratios
+    }
 }
 
 /// wallet to utilise when testing jormungandr
@@ -75,6 +90,7 @@ impl WalletTemplate {
 pub struct Wallet {
     inner: Inner,
     template: WalletTemplate,
+    discrimination: Discrimination,
 }
 
 impl Wallet {
@@ -96,6 +112,7 @@ impl Wallet {
         Wallet {
             inner: Inner::Account(AccountWallet::generate(rng)),
             template,
+            discrimination: Discrimination::Test,
         }
     }
 
@@ -106,11 +123,34 @@ impl Wallet {
         Wallet {
             inner: Inner::UTxO(UtxOWallet::generate(rng)),
             template,
+            discrimination: Discrimination::Test,
+        }
+    }
+
+    /// wallet of the type of the `template` built from a known secret key,
+    /// so its address is fully predictable
+    pub fn from_secret_key(
+        template: WalletTemplate,
+        secret_key: SecretKey<Ed25519>,
+        discrimination: Discrimination,
+    ) -> Self {
+        let inner = match template.wallet_type() {
+            WalletType::Account => Inner::Account(AccountWallet::from_secret_key(secret_key)),
+            WalletType::UTxO => Inner::UTxO(UtxOWallet::from_secret_key(secret_key)),
+        };
+        Wallet {
+            inner,
+            template,
+            discrimination,
         }
     }
 
     pub fn address(&self) -> Address {
-        self.inner.address()
+        match &self.inner {
+            Inner::Account(account) => account.address(self.discrimination),
+            Inner::UTxO(utxo) => utxo.address(self.discrimination),
+            Inner::Delegation(delegation) => delegation.address(self.discrimination),
+        }
     }
 
     pub fn stake_key(&self) -> Option<UnspecifiedAccountIdentifier> {
@@ -121,6 +161,13 @@ impl Wallet {
         self.inner.delegation_cert_for_block0(pool_id)
     }
 
+    pub fn split_delegation_cert_for_block0(
+        &self,
+        distribution: Vec<(PoolId, u8)>,
+    ) -> Result<Initial, FragmentBuilderError> {
+        self.inner.split_delegation_cert_for_block0(distribution)
+    }
+
     pub fn template(&self) -> &WalletTemplate {
         &self.template
     }
@@ -152,3 +199,39 @@ impl Into<Inner> for Wallet {
         self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secret_key() -> SecretKey<Ed25519> {
+        SecretKey::from_binary(&[7; 32]).unwrap()
+    }
+
+    #[test]
+    fn wallets_from_the_same_secret_key_are_identical() {
+        for wallet_type in &[WalletType::Account, WalletType::UTxO] {
+            let template = WalletTemplate::new("alice", 100.into(), wallet_type.clone());
+            let first =
+                Wallet::from_secret_key(template.clone(), secret_key(), Discrimination::Test);
+            let second = Wallet::from_secret_key(template, secret_key(), Discrimination::Test);
+
+            assert_eq!(first.address(), second.address());
+            assert_eq!(first.inner.identifier(), second.inner.identifier());
+        }
+    }
+
+    #[test]
+    fn wallet_from_secret_key_uses_the_given_discrimination() {
+        for wallet_type in &[WalletType::Account, WalletType::UTxO] {
+            let template = WalletTemplate::new("alice", 100.into(), wallet_type.clone());
+            let wallet =
+                Wallet::from_secret_key(template, secret_key(), Discrimination::Production);
+
+            assert_eq!(
+                wallet.address().as_ref().discrimination(),
+                Discrimination::Production
+            );
+        }
+    }
+}
@@ -1,19 +1,22 @@
-use crate::testing::FragmentBuilderError;
+use crate::{testing::FragmentBuilderError, wallet::WalletError};
 use chain_addr::Discrimination;
+use chain_crypto::{Ed25519, SecretKey};
 use chain_impl_mockchain::{
     account,
     fee::{FeeAlgorithm, LinearFee},
+    fragment::Fragment,
     transaction::{
         Balance, Input, InputOutputBuilder, Payload, PayloadSlice, TransactionSignDataHash,
-        UnspecifiedAccountIdentifier, Witness,
+        TxBuilder, UnspecifiedAccountIdentifier, Witness,
     },
+    value::Value as ValueLib,
 };
 use jormungandr_lib::{
     crypto::{
         account::{Identifier, SigningKey},
         hash::Hash,
     },
-    interfaces::Address,
+    interfaces::{Address, Value},
 };
 
 use rand_core::{CryptoRng, RngCore};
@@ -46,6 +49,16 @@ impl Wallet {
         }
     }
 
+    pub fn from_secret_key(secret_key: SecretKey<Ed25519>) -> Self {
+        let signing_key = SigningKey::from(secret_key);
+        let identifier = signing_key.identifier();
+        Wallet {
+            signing_key,
+            identifier,
+            internal_counter: account::SpendingCounter::zero(),
+        }
+    }
+
     pub fn from_existing_account(bech32_str: &str, spending_counter: Option<u32>) -> Self {
         let signing_key = SigningKey::from_bech32_str(bech32_str).expect("bad bech32");
         let identifier = signing_key.identifier();
@@ -98,6 +111,43 @@ impl Wallet {
         )
     }
 
+    /// build a transaction spending `value + fee` from this account to `address`,
+    /// witnessed with the given spending `counter` instead of the internal one
+    pub fn account_transaction(
+        &self,
+        block0_hash: &Hash,
+        address: Address,
+        value: Value,
+        fee: Value,
+        counter: account::SpendingCounter,
+    ) -> Result<Fragment, WalletError> {
+        let input_value = (ValueLib::from(value) + ValueLib::from(fee))
+            .map_err(|_| WalletError::InputValueOverflow { value, fee })?;
+
+        let mut iobuilder = InputOutputBuilder::empty();
+        iobuilder
+            .add_input(&Input::from_account_single(
+                self.identifier().to_inner(),
+                input_value,
+            ))
+            .map_err(|_| WalletError::CannotAddInput)?;
+        iobuilder.add_output(address.into(), value.into()).unwrap();
+
+        let ios = iobuilder.build();
+        let txbuilder = TxBuilder::new()
+            .set_nopayload()
+            .set_ios(&ios.inputs, &ios.outputs);
+
+        let sign_data = txbuilder.get_auth_data_for_witness().hash();
+        let witness =
+            Witness::new_account(&block0_hash.clone().into_hash(), &sign_data, counter, |d| {
+                self.signing_key().as_ref().sign(d)
+            });
+        let witnesses = vec![witness];
+        let tx = txbuilder.set_witnesses(&witnesses).set_payload_auth(&());
+        Ok(Fragment::Transaction(tx))
+    }
+
     pub fn add_input<'a, Extra: Payload>(
         &self,
         payload: PayloadSlice<'a, Extra>,
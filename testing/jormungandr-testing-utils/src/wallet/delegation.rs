@@ -69,6 +69,15 @@ impl Wallet {
         self.signing_keys.get(i).expect("no signing key found")
     }
 
+    /// signing key of the given address, `None` if the address is not
+    /// one of this wallet
+    pub fn signing_key_for(&self, address: &Address) -> Option<&SpendingKey> {
+        let discrimination = address.as_ref().discrimination();
+        (0..self.signing_keys.len())
+            .find(|i| self.address_nth(*i, discrimination) == *address)
+            .map(|index| self.signing_key(index))
+    }
+
     pub fn last_delegation_identifier(&self) -> AccountIdentifier {
         let index = self.delegations.len() - 1;
         self.delegations.get(index).unwrap().clone()
@@ -17,8 +17,12 @@ use chain_impl_mockchain::{
     value::Value as ValueLib,
 };
 use jormungandr_lib::{
-    crypto::{account::Identifier as AccountIdentifier, hash::Hash, key::Identifier},
-    interfaces::{Address, Initial, Value},
+    crypto::{
+        account::{Identifier as AccountIdentifier, SigningKey as AccountSigningKey},
+        hash::Hash,
+        key::Identifier,
+    },
+    interfaces::{Address, Initial, UTxOInfo, Value},
 };
 
 use chain_addr::Discrimination;
@@ -47,6 +51,58 @@ pub enum WalletError {
     CannotMakeWitness,
     #[error("transaction error")]
     FragmentError(#[from] FragmentBuilderError),
+    #[error("input value overflows: {value} + {fee}")]
+    InputValueOverflow { value: Value, fee: Value },
+    #[error("no signing key in the wallet for utxo address {0}")]
+    UnknownUtxoAddress(Address),
+    #[error("cannot balance the transaction")]
+    CannotBalanceTransaction,
+    #[error("wallet is not an account wallet")]
+    NotAnAccount,
+    #[error("wallet is not a utxo wallet")]
+    NotAUtxoWallet,
+}
+
+/// signature scheme used to sign the witness of a transaction input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SigningAlgorithm {
+    Ed25519,
+    /// extended Ed25519 secret keys, as derived by the bip32 wallets
+    Ed25519Extended,
+}
+
+impl Default for SigningAlgorithm {
+    fn default() -> Self {
+        SigningAlgorithm::Ed25519
+    }
+}
+
+/// address receiving the change of a transaction
+#[derive(Debug, Clone)]
+pub enum ChangeStrategy {
+    /// send the change back to the address of the spent input
+    SameAddress,
+    /// derive a fresh address from the wallet
+    NewAddress,
+    /// send the change to the given address
+    Specified(Address),
+}
+
+/// secret key of one of the addresses of a wallet
+#[derive(Debug, Clone, Copy)]
+pub enum SpendingKeyRef<'a> {
+    Account(&'a AccountSigningKey),
+    /// key of a single or group address
+    UTxO(&'a utxo::SpendingKey),
+}
+
+impl<'a> SpendingKeyRef<'a> {
+    pub fn identifier(&self) -> Identifier<Ed25519> {
+        match self {
+            SpendingKeyRef::Account(key) => Identifier::from(key.identifier().as_ref().clone()),
+            SpendingKeyRef::UTxO(key) => key.identifier(),
+        }
+    }
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -123,6 +179,35 @@ impl Wallet {
         }
     }
 
+    /// secret key witnessing the inputs spent from `address`, `None` if the
+    /// address is not controlled by the wallet
+    pub fn signing_key_for(&self, address: &Address) -> Option<SpendingKeyRef> {
+        match self {
+            Wallet::Account(account) => {
+                if account.address(address.as_ref().discrimination()) == *address {
+                    Some(SpendingKeyRef::Account(account.signing_key()))
+                } else {
+                    None
+                }
+            }
+            Wallet::UTxO(utxo) => utxo.signing_key_for(address).map(SpendingKeyRef::UTxO),
+            Wallet::Delegation(delegation) => delegation
+                .signing_key_for(address)
+                .map(SpendingKeyRef::UTxO),
+        }
+    }
+
+    /// signature scheme of the keys witnessing the inputs of this wallet
+    pub fn signing_algorithm(&self) -> SigningAlgorithm {
+        match self {
+            Wallet::Account(account) => match account.signing_key().as_ref() {
+                EitherEd25519SecretKey::Normal(_) => SigningAlgorithm::Ed25519,
+                EitherEd25519SecretKey::Extended(_) => SigningAlgorithm::Ed25519Extended,
+            },
+            Wallet::UTxO(_) | Wallet::Delegation(_) => SigningAlgorithm::Ed25519,
+        }
+    }
+
     pub fn delegation_key(&self) -> Identifier<Ed25519> {
         match self {
             Wallet::Delegation(delegation) => {
@@ -160,6 +245,22 @@ impl Wallet {
         }
     }
 
+    /// same as `mk_witness` but fails if the keys of the wallet are not
+    /// of the requested `algorithm`
+    pub fn mk_witness_with(
+        &self,
+        algorithm: SigningAlgorithm,
+        block0_hash: &Hash,
+        signing_data: &TransactionSignDataHash,
+    ) -> Result<Witness, FragmentBuilderError> {
+        if algorithm != self.signing_algorithm() {
+            return Err(FragmentBuilderError::IncompatibleSigningAlgorithm(
+                algorithm,
+            ));
+        }
+        Ok(self.mk_witness(block0_hash, signing_data))
+    }
+
     pub fn confirm_transaction(&mut self) {
         match self {
             Wallet::Account(account) => account.increment_counter(),
@@ -178,6 +279,13 @@ impl Wallet {
         FragmentBuilder::full_delegation_cert_for_block0(&self, pool_id)
     }
 
+    pub fn split_delegation_cert_for_block0(
+        &self,
+        distribution: Vec<(PoolId, u8)>,
+    ) -> Result<Initial, FragmentBuilderError> {
+        FragmentBuilder::split_delegation_cert_for_block0(&self, distribution)
+    }
+
     pub fn transaction_to(
         &mut self,
         block0_hash: &Hash,
@@ -190,6 +298,52 @@ impl Wallet {
             .map_err(WalletError::FragmentError)
     }
 
+    pub fn transaction_to_signed_with(
+        &mut self,
+        block0_hash: &Hash,
+        fees: &LinearFee,
+        address: Address,
+        value: Value,
+        algorithm: SigningAlgorithm,
+    ) -> Result<Fragment, WalletError> {
+        FragmentBuilder::new(block0_hash, fees)
+            .transaction_signed_with(&self, address, value, algorithm)
+            .map_err(WalletError::FragmentError)
+    }
+
+    pub fn utxo_transaction(
+        &mut self,
+        block0_hash: &Hash,
+        fees: &LinearFee,
+        utxo: &UTxOInfo,
+        address: Address,
+        value: Value,
+        change_strategy: ChangeStrategy,
+    ) -> Result<Fragment, WalletError> {
+        match self {
+            Wallet::UTxO(wallet) => {
+                wallet.transaction_to(block0_hash, fees, utxo, address, value, change_strategy)
+            }
+            _ => Err(WalletError::NotAUtxoWallet),
+        }
+    }
+
+    pub fn account_transaction(
+        &self,
+        block0_hash: &Hash,
+        address: Address,
+        value: Value,
+        fee: Value,
+        counter: u32,
+    ) -> Result<Fragment, WalletError> {
+        match self {
+            Wallet::Account(account) => {
+                account.account_transaction(block0_hash, address, value, fee, counter.into())
+            }
+            _ => Err(WalletError::NotAnAccount),
+        }
+    }
+
     pub fn issue_pool_retire_cert(
         &mut self,
         block0_hash: &Hash,
@@ -281,3 +435,88 @@ impl Into<WalletLib> for Wallet {
         WalletLib::from_address_data_value(address_data_value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signing_key_for_picks_the_key_of_each_address() {
+        let mut utxo = utxo::Wallet::generate(&mut rand::rngs::OsRng);
+        utxo.generate_new_signing_key();
+        let first = utxo.address_nth(0, Discrimination::Test);
+        let second = utxo.address_nth(1, Discrimination::Test);
+        let wallet = Wallet::UTxO(utxo.clone());
+
+        for (index, address) in [first, second].iter().enumerate() {
+            let key = wallet
+                .signing_key_for(address)
+                .expect("address not controlled by the wallet");
+            assert_eq!(key.identifier(), utxo.signing_key(index).identifier());
+        }
+
+        let other = Wallet::new_utxo(&mut rand::rngs::OsRng);
+        assert!(wallet.signing_key_for(&other.address()).is_none());
+    }
+
+    #[test]
+    fn unbalanced_transaction_does_not_generate_a_change_address() {
+        let mut wallet = utxo::Wallet::generate(&mut rand::rngs::OsRng);
+        let utxo = UTxOInfo::new(
+            Hash::from([1; 32]),
+            0,
+            wallet.address(Discrimination::Test),
+            1.into(),
+        );
+        let receiver = Wallet::new_utxo(&mut rand::rngs::OsRng);
+
+        let result = wallet.transaction_to(
+            &Hash::from([0; 32]),
+            &LinearFee::new(0, 0, 0),
+            &utxo,
+            receiver.address(),
+            10.into(),
+            ChangeStrategy::NewAddress,
+        );
+
+        assert!(matches!(result, Err(WalletError::CannotBalanceTransaction)));
+        assert_eq!(
+            wallet.last_address(Discrimination::Test),
+            wallet.address(Discrimination::Test)
+        );
+    }
+
+    #[test]
+    fn account_transaction_from_utxo_wallet_is_an_error() {
+        let wallet = Wallet::new_utxo(&mut rand::rngs::OsRng);
+        let receiver = Wallet::new_account(&mut rand::rngs::OsRng);
+
+        let result = wallet.account_transaction(
+            &Hash::from([0; 32]),
+            receiver.address(),
+            1.into(),
+            0.into(),
+            0,
+        );
+
+        assert!(matches!(result, Err(WalletError::NotAnAccount)));
+    }
+
+    #[test]
+    fn utxo_transaction_from_account_wallet_is_an_error() {
+        let mut wallet = Wallet::new_account(&mut rand::rngs::OsRng);
+        let utxo = UTxOInfo::new(Hash::from([1; 32]), 0, wallet.address(), 1.into());
+        let receiver = Wallet::new_utxo(&mut rand::rngs::OsRng);
+
+        let result = wallet.utxo_transaction(
+            &Hash::from([0; 32]),
+            &LinearFee::new(0, 0, 0),
+            &utxo,
+            receiver.address(),
+            1.into(),
+            ChangeStrategy::NewAddress,
+        );
+
+        assert!(matches!(result, Err(WalletError::NotAUtxoWallet)));
+    }
+}
@@ -1,11 +1,20 @@
+use super::{ChangeStrategy, WalletError};
 use chain_addr::Discrimination;
-use chain_impl_mockchain::transaction::{TransactionSignDataHash, Witness};
+use chain_crypto::{Blake2b256, Ed25519, SecretKey};
+use chain_impl_mockchain::{
+    fee::LinearFee,
+    fragment::Fragment,
+    transaction::{
+        Input, InputEnum, InputOutputBuilder, NoExtra, OutputPolicy, Payload,
+        TransactionSignDataHash, TxBuilder, UtxoPointer, Witness,
+    },
+};
 use jormungandr_lib::{
     crypto::{
         hash::Hash,
         key::{self, Identifier},
     },
-    interfaces::{Address, UTxOInfo},
+    interfaces::{Address, UTxOInfo, Value},
 };
 use rand_chacha::ChaChaRng;
 use rand_core::{CryptoRng, RngCore, SeedableRng};
@@ -40,6 +49,19 @@ impl Wallet {
         seed.into()
     }
 
+    /// wallet whose first address is the one of `secret_key`, the keys
+    /// generated afterward are seeded from a hash of the secret key so they
+    /// are reproducible too
+    pub fn from_secret_key(secret_key: SecretKey<Ed25519>) -> Self {
+        let seed: [u8; 32] = Blake2b256::new(secret_key.leak_secret().as_ref()).into();
+        Wallet {
+            signing_keys: vec![secret_key.into()],
+            seed,
+            rng: ChaChaRng::from_seed(seed),
+            utxos: Vec::new(),
+        }
+    }
+
     pub fn generate_new_signing_key(&mut self) -> &SpendingKey {
         let key = key::SigningKey::generate(&mut self.rng);
         self.signing_keys.push(key);
@@ -70,6 +92,22 @@ impl Wallet {
         self.signing_keys.get(index).expect("no signing key found")
     }
 
+    pub fn last_address(&self, discrimination: Discrimination) -> Address {
+        self.address_nth(self.signing_keys.len() - 1, discrimination)
+    }
+
+    /// signing key of the given address, `None` if the address is not
+    /// one of this wallet
+    pub fn signing_key_for(&self, address: &Address) -> Option<&SpendingKey> {
+        self.signing_key_index(address)
+            .map(|index| self.signing_key(index))
+    }
+
+    fn signing_key_index(&self, address: &Address) -> Option<usize> {
+        let discrimination = address.as_ref().discrimination();
+        (0..self.signing_keys.len()).find(|i| self.address_nth(*i, discrimination) == *address)
+    }
+
     pub fn mk_witness(
         &self,
         block0_hash: &Hash,
@@ -79,6 +117,73 @@ impl Wallet {
             self.last_signing_key().as_ref().sign(d)
         })
     }
+
+    /// build a transaction spending `utxo` to `address`, sending what is left
+    /// after `value` and fees to the address selected by `change_strategy`
+    pub fn transaction_to(
+        &mut self,
+        block0_hash: &Hash,
+        fees: &LinearFee,
+        utxo: &UTxOInfo,
+        address: Address,
+        value: Value,
+        change_strategy: ChangeStrategy,
+    ) -> Result<Fragment, WalletError> {
+        let key_index = self
+            .signing_key_index(utxo.address())
+            .ok_or_else(|| WalletError::UnknownUtxoAddress(utxo.address().clone()))?;
+
+        // the new key is only kept once the transaction is balanced
+        let mut rng = self.rng.clone();
+        let (change_address, change_key) = match change_strategy {
+            ChangeStrategy::SameAddress => (utxo.address().clone(), None),
+            ChangeStrategy::NewAddress => {
+                let key = SpendingKey::generate(&mut rng);
+                let address = key
+                    .identifier()
+                    .to_single_address(utxo.address().as_ref().discrimination())
+                    .into();
+                (address, Some(key))
+            }
+            ChangeStrategy::Specified(address) => (address, None),
+        };
+
+        let mut iobuilder = InputOutputBuilder::empty();
+        iobuilder
+            .add_input(&Input::from_enum(InputEnum::UtxoInput(UtxoPointer {
+                output_index: utxo.index_in_transaction(),
+                transaction_id: utxo.transaction_id().clone().into_hash(),
+                value: (*utxo.associated_fund()).into(),
+            })))
+            .map_err(|_| WalletError::CannotAddInput)?;
+        iobuilder.add_output(address.into(), value.into()).unwrap();
+
+        let payload_data = NoExtra.payload_data();
+        let (_, _, ios) = iobuilder
+            .seal_with_output_policy(
+                payload_data.borrow(),
+                fees,
+                OutputPolicy::One(change_address.into()),
+            )
+            .map_err(|_| WalletError::CannotBalanceTransaction)?;
+
+        if let Some(key) = change_key {
+            self.rng = rng;
+            self.signing_keys.push(key);
+        }
+
+        let txbuilder = TxBuilder::new()
+            .set_nopayload()
+            .set_ios(&ios.inputs, &ios.outputs);
+
+        let sign_data = txbuilder.get_auth_data_for_witness().hash();
+        let witness = Witness::new_utxo(&block0_hash.clone().into_hash(), &sign_data, |d| {
+            self.signing_key(key_index).as_ref().sign(d)
+        });
+        let witnesses = vec![witness];
+        let tx = txbuilder.set_witnesses(&witnesses).set_payload_auth(&());
+        Ok(Fragment::Transaction(tx))
+    }
 }
 
 impl From<[u8; 32]> for Wallet {